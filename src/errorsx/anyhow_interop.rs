@@ -0,0 +1,79 @@
+//! Interoperability between [`ErrorX`] and `anyhow::Error`
+//!
+//! Available with the `anyhow` feature. Since [`ErrorX`] is `Error + Send + Sync + 'static`,
+//! anyhow's blanket conversion already lowers it into `anyhow::Error` with `?` or `.into()`.
+//! [`ErrorX::from_anyhow`] converts in the other direction.
+//!
+//! # Example
+//! ```
+//! # use crate::toolkit::errorsx::ErrorX;
+//!
+//! let err: anyhow::Error = ErrorX::new("Operation failed").into();
+//! let err = ErrorX::from_anyhow(err);
+//! assert_eq!(err.message(), "Operation failed");
+//! ```
+
+use super::ErrorX;
+
+impl ErrorX {
+    /// Converts an `anyhow::Error` into an ErrorX
+    ///
+    /// If the anyhow error wraps an ErrorX, the original is returned unchanged. Otherwise the
+    /// top-level message becomes the ErrorX message and each cause in the anyhow chain is
+    /// captured as a context string, outermost first.
+    ///
+    /// # Parameters
+    /// * `e` - The anyhow error to convert
+    ///
+    /// # Returns
+    /// * `Self` - The converted ErrorX instance
+    #[track_caller]
+    pub fn from_anyhow(e: anyhow::Error) -> Self {
+        match e.downcast::<ErrorX>() {
+            Ok(err) => err,
+            Err(e) => e
+                .chain()
+                .skip(1)
+                .fold(ErrorX::builder(e.to_string()), |builder, cause| {
+                    builder.with_context(cause.to_string())
+                })
+                .build(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+    use std::io;
+
+    #[test]
+    fn test_into_anyhow_round_trip() {
+        let err = ErrorX::builder("Operation failed")
+            .with_status_code(503)
+            .build();
+        let lowered: anyhow::Error = err.into();
+        assert!(lowered.to_string().contains("Operation failed"));
+
+        let err = ErrorX::from_anyhow(lowered);
+        assert_eq!(err.message(), "Operation failed");
+        assert_eq!(err.status_code(), &Some(503));
+    }
+
+    #[test]
+    fn test_from_anyhow_chain() {
+        let result: Result<(), io::Error> = Err(io::Error::other("disk unavailable"));
+        let e = result
+            .context("reading config")
+            .context("starting server")
+            .unwrap_err();
+
+        let err = ErrorX::from_anyhow(e);
+        assert_eq!(err.message(), "starting server");
+        assert_eq!(
+            err.context(),
+            &vec!["reading config".to_string(), "disk unavailable".to_string()]
+        );
+    }
+}
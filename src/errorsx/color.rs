@@ -0,0 +1,85 @@
+//! Colored terminal reports for [`ErrorX`]
+//!
+//! Available with the `color` feature. Reports use ANSI escape codes: messages in bold red,
+//! locations dimmed and context in yellow. Following the [`NO_COLOR`](https://no-color.org)
+//! convention, a non-empty `NO_COLOR` environment variable turns colors off.
+//!
+//! # Example
+//! ```
+//! # use crate::toolkit::errorsx::ErrorX;
+//!
+//! let err = ErrorX::builder("Failed to read config")
+//!     .with_context("Starting up")
+//!     .build();
+//! eprintln!("{}", err.report_colored());
+//! ```
+
+use super::{ErrorX, ReportStyle};
+
+/// Style for reports written to a color terminal
+const COLORED: ReportStyle = ReportStyle {
+    message: "\x1b[1;31m",
+    location: "\x1b[2m",
+    context: "\x1b[33m",
+    reset: "\x1b[0m",
+};
+
+/// Returns whether the user opted out of colored output through `NO_COLOR`
+fn no_color() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+impl ErrorX {
+    /// Renders the report described in [`ErrorX::report`] with ANSI colors
+    ///
+    /// Falls back to the plain report when the `NO_COLOR` environment variable is set to a
+    /// non-empty value.
+    ///
+    /// # Returns
+    /// * `String` - The rendered report
+    pub fn report_colored(&self) -> String {
+        self.report_colored_with(!no_color())
+    }
+
+    /// Renders the colored report, or the plain one when `enabled` is `false`
+    fn report_colored_with(&self, enabled: bool) -> String {
+        if enabled {
+            self.render_report(&COLORED)
+        } else {
+            self.report()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stringsx::ansi::{has_ansi, strip_ansi};
+
+    fn chained_error() -> ErrorX {
+        let inner = ErrorX::builder("Connection refused")
+            .with_context("Connecting to db")
+            .build();
+        ErrorX::builder("Query failed")
+            .with_source(inner)
+            .with_backtrace(false)
+            .build()
+    }
+
+    #[test]
+    fn test_report_colored_disabled() {
+        let err = chained_error();
+        let plain = err.report_colored_with(false);
+        assert!(!has_ansi(&plain));
+        assert_eq!(plain, err.report());
+    }
+
+    #[test]
+    fn test_report_colored_enabled() {
+        let err = chained_error();
+        let colored = err.report_colored_with(true);
+        assert!(colored.contains("\x1b[1;31m[0] Query failed\x1b[0m"));
+        assert!(colored.contains("\x1b[33mContext: Connecting to db\x1b[0m"));
+        assert_eq!(strip_ansi(&colored), err.report());
+    }
+}
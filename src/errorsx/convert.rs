@@ -0,0 +1,80 @@
+//! Conversions from common standard library errors into [`ErrorX`]
+//!
+//! Each conversion sets a default message describing the kind of failure and keeps the original
+//! error as the source, so `?` can be used on these errors in functions returning
+//! `Result<_, ErrorX>`.
+//!
+//! # Example
+//! ```
+//! # use crate::toolkit::errorsx::ErrorX;
+//!
+//! fn parse_port(s: &str) -> Result<u16, ErrorX> {
+//!     Ok(s.parse::<u16>()?)
+//! }
+//!
+//! let err = parse_port("http").unwrap_err();
+//! assert_eq!(err.message(), "Failed to parse integer");
+//! ```
+
+use std::{fmt, io, net, num, str, string, time};
+
+use super::ErrorX;
+
+/// Implements `From<$error> for ErrorX` with a default message, keeping the error as source
+macro_rules! impl_from_std_error {
+    ($($error:ty => $message:literal),+ $(,)?) => {
+        $(
+            impl From<$error> for ErrorX {
+                #[track_caller]
+                fn from(err: $error) -> Self {
+                    ErrorX::builder($message).with_source(err).build()
+                }
+            }
+        )+
+    };
+}
+
+impl_from_std_error! {
+    io::Error => "I/O error",
+    fmt::Error => "Formatting error",
+    num::ParseIntError => "Failed to parse integer",
+    num::ParseFloatError => "Failed to parse float",
+    num::TryFromIntError => "Integer conversion out of range",
+    str::ParseBoolError => "Failed to parse boolean",
+    str::Utf8Error => "Invalid UTF-8",
+    string::FromUtf8Error => "Invalid UTF-8",
+    net::AddrParseError => "Failed to parse network address",
+    time::SystemTimeError => "System time error",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_from_io_error() {
+        let io_error = io::Error::new(io::ErrorKind::NotFound, "File not found");
+        let err: ErrorX = io_error.into();
+
+        assert_eq!(err.message(), "I/O error");
+        assert_eq!(err.source().unwrap().to_string(), "File not found");
+        assert_eq!(err.location().file(), "src/errorsx/convert.rs");
+    }
+
+    #[test]
+    fn test_from_with_question_mark() {
+        fn parse(s: &str) -> Result<i32, ErrorX> {
+            Ok(s.parse::<i32>()?)
+        }
+
+        assert_eq!(parse("42").unwrap(), 42);
+        let err = parse("forty-two").unwrap_err();
+        assert_eq!(err.message(), "Failed to parse integer");
+        assert!(err
+            .source()
+            .unwrap()
+            .downcast_ref::<num::ParseIntError>()
+            .is_some());
+    }
+}
@@ -0,0 +1,159 @@
+//! Tracing integration for [`ErrorX`]
+//!
+//! Available with the `tracing` feature. [`ErrorX::emit`] and [`ErrorX::emit_with_level`]
+//! record the error as a `tracing` event with the following structured fields, so log
+//! backends can query them individually:
+//!
+//! - `message` - The error message
+//! - `location` - The creation site rendered as `file:line`
+//! - `context` - The plain, key-value and redacted secret context joined with commas
+//! - `status_code` - The status code, omitted when unset
+//!
+//! # Example
+//! ```
+//! # use crate::toolkit::errorsx::ErrorX;
+//! use tracing::Level;
+//!
+//! let err = ErrorX::builder("Payment declined")
+//!     .with_status_code(402)
+//!     .build();
+//! err.emit_with_level(Level::WARN);
+//! ```
+
+use tracing::{event, Level};
+
+use super::{ErrorX, Severity};
+
+impl ErrorX {
+    /// Records the error as a tracing event at a level derived from its severity
+    ///
+    /// `Trace`, `Debug`, `Info` and `Warn` map to the matching tracing levels, while `Error`,
+    /// `Critical` and an unset severity are recorded at `ERROR`.
+    pub fn emit(&self) {
+        let level = match self.severity {
+            Some(Severity::Trace) => Level::TRACE,
+            Some(Severity::Debug) => Level::DEBUG,
+            Some(Severity::Info) => Level::INFO,
+            Some(Severity::Warn) => Level::WARN,
+            Some(Severity::Error) | Some(Severity::Critical) | None => Level::ERROR,
+        };
+        self.emit_with_level(level);
+    }
+
+    /// Records the error as a tracing event at the given level
+    ///
+    /// # Parameters
+    /// * `level` - The level to record the event at
+    pub fn emit_with_level(&self, level: Level) {
+        let location = format!("{}:{}", self.location.file(), self.location.line());
+        let context = self.context_info();
+
+        // Event levels must be known at compile time, so dispatch on each level explicitly
+        macro_rules! emit_at {
+            ($level:expr) => {
+                event!(
+                    $level,
+                    message = %self.message,
+                    location = %location,
+                    context = %context,
+                    status_code = self.status_code,
+                )
+            };
+        }
+        match level {
+            Level::TRACE => emit_at!(Level::TRACE),
+            Level::DEBUG => emit_at!(Level::DEBUG),
+            Level::INFO => emit_at!(Level::INFO),
+            Level::WARN => emit_at!(Level::WARN),
+            Level::ERROR => emit_at!(Level::ERROR),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        collections::HashMap,
+        fmt::Debug,
+        sync::{Arc, Mutex},
+    };
+    use tracing::{
+        field::{Field, Visit},
+        Event, Subscriber,
+    };
+    use tracing_subscriber::{layer::Context, prelude::*, Layer};
+
+    /// A captured event, with its level and every recorded field rendered as a string
+    type Captured = (Level, HashMap<String, String>);
+
+    /// Collects the fields of each event it sees
+    #[derive(Clone, Default)]
+    struct CaptureLayer(Arc<Mutex<Vec<Captured>>>);
+
+    struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_u64(&mut self, field: &Field, value: u64) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for CaptureLayer {
+        fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+            let mut fields = HashMap::new();
+            event.record(&mut FieldVisitor(&mut fields));
+            self.0
+                .lock()
+                .unwrap()
+                .push((*event.metadata().level(), fields));
+        }
+    }
+
+    fn capture(f: impl FnOnce()) -> Vec<Captured> {
+        let layer = CaptureLayer::default();
+        let subscriber = tracing_subscriber::registry().with(layer.clone());
+        tracing::subscriber::with_default(subscriber, f);
+        let events = layer.0.lock().unwrap().clone();
+        events
+    }
+
+    #[test]
+    fn test_emit_with_level() {
+        let err = ErrorX::builder("Payment declined")
+            .with_context("Charging card")
+            .with_context_kv("order_id", "42")
+            .with_status_code(402)
+            .build();
+        let events = capture(|| err.emit_with_level(Level::WARN));
+
+        assert_eq!(events.len(), 1);
+        let (level, fields) = &events[0];
+        assert_eq!(*level, Level::WARN);
+        assert_eq!(fields["message"], "Payment declined");
+        assert!(fields["location"].starts_with("src/errorsx/emit.rs:"));
+        assert_eq!(fields["context"], "Charging card,order_id=42");
+        assert_eq!(fields["status_code"], "402");
+    }
+
+    #[test]
+    fn test_emit_uses_severity() {
+        let warning = ErrorX::builder("Slow query")
+            .with_severity(Severity::Warn)
+            .build();
+        let plain = ErrorX::new("Unexpected failure");
+        let events = capture(|| {
+            warning.emit();
+            plain.emit();
+        });
+
+        assert_eq!(events[0].0, Level::WARN);
+        assert_eq!(events[1].0, Level::ERROR);
+        assert!(!events[1].1.contains_key("status_code"));
+    }
+}
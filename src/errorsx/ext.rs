@@ -0,0 +1,168 @@
+//! Extension traits for converting foreign results and options into [`ErrorX`]
+//!
+//! - [`Context`]: Wraps the error of a `Result` into an [`ErrorX`] with added context
+//! - [`OptionExt`]: Turns a `None` into an [`ErrorX`] with a message
+//!
+//! # Example
+//! ```
+//! # use std::fs;
+//! # use crate::toolkit::errorsx::{Context, ErrorX};
+//!
+//! fn read_config() -> Result<String, ErrorX> {
+//!     fs::read_to_string("/no/such/config.toml").with_errorx_context("while reading config")
+//! }
+//!
+//! let err = read_config().unwrap_err();
+//! assert_eq!(err.context(), &vec!["while reading config".to_string()]);
+//! ```
+
+use std::error::Error;
+
+use super::ErrorX;
+
+/// Adds context to the error of a `Result`, converting it into an [`ErrorX`]
+pub trait Context<T> {
+    /// Converts the error into an ErrorX with the given context appended
+    ///
+    /// The original error is kept as the source and its message becomes the ErrorX message.
+    /// The location of the caller is captured as the error location. `Ok` values pass through
+    /// untouched.
+    ///
+    /// # Parameters
+    /// * `ctx` - The context string to append
+    ///
+    /// # Returns
+    /// * `Result<T, ErrorX>` - The original value, or the wrapped error
+    fn with_errorx_context(self, ctx: impl Into<String>) -> Result<T, ErrorX>;
+}
+
+impl<T, E> Context<T> for Result<T, E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    #[track_caller]
+    fn with_errorx_context(self, ctx: impl Into<String>) -> Result<T, ErrorX> {
+        match self {
+            Ok(value) => Ok(value),
+            Err(err) => {
+                // Reuse the bare message of a wrapped ErrorX rather than its full Display
+                let message = match (&err as &(dyn Error + 'static)).downcast_ref::<ErrorX>() {
+                    Some(inner) => inner.message().to_string(),
+                    None => err.to_string(),
+                };
+                Err(ErrorX::builder(message)
+                    .with_context(ctx)
+                    .with_source(err)
+                    .build())
+            }
+        }
+    }
+}
+
+/// Converts a missing `Option` value into an [`ErrorX`]
+pub trait OptionExt<T> {
+    /// Converts `None` into an ErrorX with the given message
+    ///
+    /// The location of the caller is captured as the error location. `Some` values pass
+    /// through untouched.
+    ///
+    /// # Parameters
+    /// * `message` - The error message used when the value is missing
+    ///
+    /// # Returns
+    /// * `Result<T, ErrorX>` - The contained value, or the error
+    fn ok_or_errorx(self, message: impl Into<String>) -> Result<T, ErrorX>;
+
+    /// Converts `None` into an ErrorX with a message computed on demand
+    ///
+    /// Like [`OptionExt::ok_or_errorx`], but the closure is only called when the value is
+    /// missing, which avoids formatting the message on the success path.
+    ///
+    /// # Parameters
+    /// * `f` - Closure producing the error message
+    ///
+    /// # Returns
+    /// * `Result<T, ErrorX>` - The contained value, or the error
+    fn ok_or_errorx_with<M: Into<String>>(self, f: impl FnOnce() -> M) -> Result<T, ErrorX>;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    #[track_caller]
+    fn ok_or_errorx(self, message: impl Into<String>) -> Result<T, ErrorX> {
+        match self {
+            Some(value) => Ok(value),
+            None => Err(ErrorX::new(message)),
+        }
+    }
+
+    #[track_caller]
+    fn ok_or_errorx_with<M: Into<String>>(self, f: impl FnOnce() -> M) -> Result<T, ErrorX> {
+        match self {
+            Some(value) => Ok(value),
+            None => Err(ErrorX::new(f())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn test_with_errorx_context_ok() {
+        let result: Result<u32, io::Error> = Ok(7);
+        assert_eq!(result.with_errorx_context("unused").unwrap(), 7);
+    }
+
+    #[test]
+    fn test_with_errorx_context_err() {
+        let result: Result<u32, io::Error> = Err(io::Error::other("disk unavailable"));
+        let err = result.with_errorx_context("while doing thing").unwrap_err();
+
+        assert_eq!(err.message(), "disk unavailable");
+        assert_eq!(err.context(), &vec!["while doing thing".to_string()]);
+        assert_eq!(err.source().unwrap().to_string(), "disk unavailable");
+        assert_eq!(err.location().file(), "src/errorsx/ext.rs");
+        assert_eq!(err.location().line(), line!() - 6);
+    }
+
+    #[test]
+    fn test_with_errorx_context_wraps_errorx() {
+        let result: Result<(), ErrorX> = Err(ErrorX::new("Inner Error"));
+        let err = result.with_errorx_context("Outer context").unwrap_err();
+
+        assert_eq!(err.message(), "Inner Error");
+        assert!(err.source().unwrap().downcast_ref::<ErrorX>().is_some());
+    }
+
+    #[test]
+    fn test_ok_or_errorx_some() {
+        assert_eq!(Some(7).ok_or_errorx("unused").unwrap(), 7);
+        assert_eq!(
+            Some(7)
+                .ok_or_errorx_with(|| -> String { unreachable!() })
+                .unwrap(),
+            7
+        );
+    }
+
+    #[test]
+    fn test_ok_or_errorx_none() {
+        use std::collections::HashMap;
+
+        let map: HashMap<&str, u32> = HashMap::new();
+        let err = map.get("id").ok_or_errorx("missing key").unwrap_err();
+        assert_eq!(err.message(), "missing key");
+        assert_eq!(err.location().file(), "src/errorsx/ext.rs");
+        assert_eq!(err.location().line(), line!() - 3);
+
+        let key = "name";
+        let err = map
+            .get(key)
+            .ok_or_errorx_with(|| format!("missing key {:?}", key))
+            .unwrap_err();
+        assert_eq!(err.message(), "missing key \"name\"");
+        assert_eq!(err.location().file(), "src/errorsx/ext.rs");
+    }
+}
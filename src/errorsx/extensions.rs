@@ -0,0 +1,34 @@
+//! Typed extension storage for [`ErrorX`](super::ErrorX) values
+//!
+//! Extensions hold at most one value per type, similar to `http::Extensions`. Values are
+//! shared behind an `Arc`, so cloning an error is cheap and keeps its extensions.
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::Arc,
+};
+
+/// A map from a type to a single value of that type
+#[derive(Clone, Default)]
+pub(super) struct Extensions(HashMap<TypeId, Arc<dyn Any + Send + Sync>>);
+
+impl Extensions {
+    /// Stores a value, replacing any previous value of the same type
+    pub(super) fn insert<T: Send + Sync + 'static>(&mut self, value: T) {
+        self.0.insert(TypeId::of::<T>(), Arc::new(value));
+    }
+
+    /// Returns the stored value of type `T`, if any
+    pub(super) fn get<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.0.get(&TypeId::of::<T>())?.downcast_ref::<T>()
+    }
+}
+
+impl std::fmt::Debug for Extensions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Extensions")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
@@ -0,0 +1,158 @@
+//! gRPC integration for [`ErrorX`]
+//!
+//! Available with the `tonic` feature. [`ErrorX`] converts into `tonic::Status`, so it can be
+//! returned from gRPC service handlers with `?` or `.into()`. The status message is the error
+//! message and the code is chosen as follows:
+//!
+//! - Status codes from `1` to `16` are taken to be gRPC codes and used directly
+//! - Other status codes are treated as HTTP codes and mapped to their closest gRPC code
+//! - An unset status code becomes `Internal`
+//!
+//! An error is never reported as `Ok`: a status code of `0` or a 2xx HTTP code becomes
+//! `Unknown`, since clients would otherwise read the failure as a success.
+//!
+//! The context, including redacted secret context, is attached as the `x-error-context-bin`
+//! binary metadata entry, and the error code as the `x-error-code` entry.
+//!
+//! # Example
+//! ```
+//! # use crate::toolkit::errorsx::ErrorX;
+//!
+//! let err = ErrorX::builder("User not found")
+//!     .with_status_code(404)
+//!     .build();
+//! let status = tonic::Status::from(err);
+//! assert_eq!(status.code(), tonic::Code::NotFound);
+//! assert_eq!(status.message(), "User not found");
+//! ```
+
+use tonic::{
+    metadata::{AsciiMetadataValue, BinaryMetadataValue},
+    Code, Status,
+};
+
+use super::ErrorX;
+
+/// Maps an HTTP status code to the closest gRPC code
+fn code_from_http(status_code: u32) -> Code {
+    match status_code {
+        400 => Code::InvalidArgument,
+        401 => Code::Unauthenticated,
+        403 => Code::PermissionDenied,
+        404 => Code::NotFound,
+        409 => Code::AlreadyExists,
+        412 => Code::FailedPrecondition,
+        413 => Code::ResourceExhausted,
+        416 => Code::OutOfRange,
+        429 => Code::ResourceExhausted,
+        499 => Code::Cancelled,
+        501 => Code::Unimplemented,
+        503 => Code::Unavailable,
+        504 => Code::DeadlineExceeded,
+        // Fall back on the status class for codes without a specific counterpart
+        _ => match status_code / 100 {
+            4 => Code::FailedPrecondition,
+            5 => Code::Internal,
+            _ => Code::Unknown,
+        },
+    }
+}
+
+impl From<ErrorX> for Status {
+    fn from(err: ErrorX) -> Self {
+        let code = match err.status_code {
+            Some(status_code @ 1..=16) => Code::from_i32(status_code as i32),
+            Some(status_code) => code_from_http(status_code),
+            None => Code::Internal,
+        };
+        let mut status = Status::new(code, err.message.clone());
+
+        let context_info = err.context_info();
+        if !context_info.is_empty() {
+            status.metadata_mut().insert_bin(
+                "x-error-context-bin",
+                BinaryMetadataValue::from_bytes(context_info.as_bytes()),
+            );
+        }
+        // Codes that are not valid ASCII metadata are left out rather than mangled
+        if let Some(value) = err
+            .code
+            .as_deref()
+            .and_then(|code| AsciiMetadataValue::try_from(code).ok())
+        {
+            status.metadata_mut().insert("x-error-code", value);
+        }
+        status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_status_from_http_code() {
+        let err = ErrorX::builder("User not found")
+            .with_context("Loading profile")
+            .with_context_kv("user_id", "42")
+            .with_status_code(404)
+            .with_code("USER_NOT_FOUND")
+            .build();
+        let status = Status::from(err);
+
+        assert_eq!(status.code(), Code::NotFound);
+        assert_eq!(status.message(), "User not found");
+        assert_eq!(
+            status.metadata().get("x-error-code").unwrap(),
+            "USER_NOT_FOUND"
+        );
+        let context = status
+            .metadata()
+            .get_bin("x-error-context-bin")
+            .unwrap()
+            .to_bytes()
+            .unwrap();
+        assert_eq!(&context[..], b"Loading profile,user_id=42");
+    }
+
+    #[test]
+    fn test_into_status_from_grpc_code() {
+        let err = ErrorX::builder("Try again later")
+            .with_status_code(14)
+            .build();
+        assert_eq!(Status::from(err).code(), Code::Unavailable);
+    }
+
+    #[test]
+    fn test_into_status_fallbacks() {
+        let status = Status::from(ErrorX::new("Unexpected failure"));
+        assert_eq!(status.code(), Code::Internal);
+        assert!(status.metadata().get_bin("x-error-context-bin").is_none());
+        assert!(status.metadata().get("x-error-code").is_none());
+
+        let err = ErrorX::builder("I'm a teapot")
+            .with_status_code(418)
+            .build();
+        assert_eq!(Status::from(err).code(), Code::FailedPrecondition);
+        let err = ErrorX::builder("Bad gateway").with_status_code(502).build();
+        assert_eq!(Status::from(err).code(), Code::Internal);
+    }
+
+    #[test]
+    fn test_into_status_payload_too_large() {
+        let err = ErrorX::builder("Payload too large")
+            .with_status_code(413)
+            .build();
+        assert_eq!(Status::from(err).code(), Code::ResourceExhausted);
+    }
+
+    #[test]
+    fn test_into_status_never_ok() {
+        for status_code in [0, 200, 201, 204, 299] {
+            let err = ErrorX::builder("Failed")
+                .with_status_code(status_code)
+                .build();
+            assert_eq!(Status::from(err).code(), Code::Unknown, "{status_code}");
+        }
+    }
+}
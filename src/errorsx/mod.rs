@@ -10,6 +10,19 @@
 //! - Original source errors
 //! - Status codes and messages
 //!
+//! Common standard library errors such as [`std::io::Error`] convert into [`ErrorX`] with `?`,
+//! the [`Context`] trait wraps any other error with added context and the [`OptionExt`] trait
+//! turns a missing `Option` value into an error.
+//!
+//! With the `serde` feature enabled, [`ErrorX`] also implements `serde::Serialize`, with the
+//! `axum` feature it implements `axum::response::IntoResponse`, with the `anyhow` feature it
+//! converts to and from `anyhow::Error`, with the `tracing` feature it can be recorded as a
+//! structured `tracing` event, and with the `tonic` feature it converts into `tonic::Status`.
+//!
+//! The `no-backtrace` feature turns backtrace capture off entirely, for release builds that
+//! never symbolize them. The API is unchanged and [`ErrorX::backtrace`] returns a disabled
+//! backtrace.
+//!
 //! # Example
 //! ```
 //! # use std::io;
@@ -23,7 +36,113 @@
 //!     .build();
 //! ```
 
-use std::{backtrace::Backtrace, error::Error, fmt::Display, panic::Location};
+use std::{
+    backtrace::{Backtrace, BacktraceStatus},
+    error::Error,
+    fmt::Display,
+    panic::Location,
+    time::SystemTime,
+};
+
+#[cfg(feature = "anyhow")]
+mod anyhow_interop;
+#[cfg(feature = "color")]
+mod color;
+mod convert;
+#[cfg(feature = "tracing")]
+mod emit;
+mod ext;
+mod extensions;
+#[cfg(feature = "tonic")]
+mod grpc;
+mod process;
+#[cfg(feature = "axum")]
+mod response;
+#[cfg(feature = "serde")]
+mod serialize;
+mod severity;
+mod status;
+mod timestamp;
+
+pub use ext::{Context, OptionExt};
+use extensions::Extensions;
+pub use severity::Severity;
+
+/// Upper bound on the number of links followed when walking a source chain
+const MAX_CHAIN_DEPTH: usize = 128;
+
+/// Prefix marking context entries inherited from another error
+const INHERITED_CONTEXT_PREFIX: &str = "inner";
+
+/// Placeholder rendered in place of secret context values
+const REDACTED: &str = "***";
+
+/// A sensitive context value that is redacted from `Debug` output
+#[derive(Clone)]
+struct Secret(String);
+
+impl std::fmt::Debug for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+/// A source error preserved only as its rendered message
+///
+/// Used for sources that cannot be cloned and for `Display` values that are not errors.
+#[derive(Debug)]
+struct SourceMessage(String);
+
+impl Display for SourceMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for SourceMessage {}
+
+/// Escape codes written around each part of a report, all empty for plain output
+struct ReportStyle {
+    message: &'static str,
+    location: &'static str,
+    context: &'static str,
+    reset: &'static str,
+}
+
+impl ReportStyle {
+    /// Style for reports without any escape codes
+    const PLAIN: Self = Self {
+        message: "",
+        location: "",
+        context: "",
+        reset: "",
+    };
+}
+
+/// A context entry collected by the builder, either ready or computed at build time
+enum PendingContext {
+    Ready(String),
+    Lazy(Box<dyn FnOnce() -> String + Send + Sync>),
+}
+
+impl PendingContext {
+    /// Resolves the entry into its context string, invoking a lazy closure
+    fn resolve(self) -> String {
+        match self {
+            PendingContext::Ready(context) => context,
+            PendingContext::Lazy(f) => f(),
+        }
+    }
+}
+
+impl std::fmt::Debug for PendingContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PendingContext::Ready(context) => write!(f, "{:?}", context),
+            PendingContext::Lazy(_) => f.write_str("<lazy>"),
+        }
+    }
+}
 
 /// A structured error type that contains message, backtrace, location and context information
 ///
@@ -32,33 +151,81 @@ use std::{backtrace::Backtrace, error::Error, fmt::Display, panic::Location};
 /// * `backtrace` - The stack backtrace when error occurred
 /// * `location` - The source code location where error was created
 /// * `context` - Vector of contextual information strings
+/// * `context_kv` - Vector of key-value contextual information pairs
+/// * `secret_context` - Vector of key-value pairs whose values are redacted when rendered
 /// * `source` - Optional underlying error that caused this error
 /// * `status_code` - Optional HTTP status code
 /// * `status` - Optional status message string
+/// * `severity` - Optional severity level
+/// * `code` - Optional stable, machine-readable error code
+/// * `retryable` - Optional explicit hint on whether the failed operation is worth retrying
+/// * `children` - Independent errors grouped under this one, see [`ErrorX::aggregate`]
+/// * `timestamp` - The wall-clock time at which the error was built
+/// * `request_id` - Optional id of the request during which the error occurred
+/// * `extensions` - Typed values attached for later retrieval, see [`ErrorX::extension`]
 #[derive(Debug)]
 pub struct ErrorX {
     message: String,
     backtrace: Backtrace,
     location: &'static Location<'static>,
     context: Vec<String>,
+    context_kv: Vec<(String, String)>,
+    secret_context: Vec<(String, Secret)>,
     source: Option<Box<dyn Error + Send + Sync>>,
     status_code: Option<u32>,
     status: Option<String>,
+    severity: Option<Severity>,
+    code: Option<String>,
+    retryable: Option<bool>,
+    children: Vec<ErrorX>,
+    timestamp: SystemTime,
+    request_id: Option<String>,
+    extensions: Extensions,
 }
 
 impl Display for ErrorX {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let context_info = self.context.join(",");
+        let context_info = self.context_info();
         let location_info = format!(
             "(at: {}, line_no:{})",
             self.location.file(),
             self.location.line()
         );
+        // The alternate flag (`{:#}`) opts into the verbose, multi-line form with the backtrace
+        let separator = if f.alternate() { ",\n" } else { ", " };
         write!(
             f,
-            "Message:{},\nLocation: {},\nContext: {},\nSource:\n {:#?}",
-            self.message, location_info, context_info, self.backtrace
-        )
+            "Message:{}{}Location: {}{}Context: {}{}Timestamp: {}",
+            self.message,
+            separator,
+            location_info,
+            separator,
+            context_info,
+            separator,
+            timestamp::format_rfc3339(self.timestamp)
+        )?;
+        if let Some(severity) = &self.severity {
+            write!(f, "{}Severity: {}", separator, severity)?;
+        }
+        if let Some(code) = &self.code {
+            write!(f, "{}Code: {}", separator, code)?;
+        }
+        if let Some(request_id) = &self.request_id {
+            write!(f, "{}Request ID: {}", separator, request_id)?;
+        }
+        if !self.children.is_empty() {
+            let messages = self
+                .children
+                .iter()
+                .map(|child| child.message.as_str())
+                .collect::<Vec<_>>()
+                .join("; ");
+            write!(f, "{}Errors: {}", separator, messages)?;
+        }
+        if f.alternate() {
+            write!(f, ",\nSource:\n {:#?}", self.backtrace)?;
+        }
+        Ok(())
     }
 }
 
@@ -70,23 +237,79 @@ impl Error for ErrorX {
     }
 }
 
+/// Clones the error with a freshly captured backtrace
+///
+/// Backtraces cannot be cloned, so a new one is captured unless the original was disabled.
+/// A source ErrorX is cloned recursively, while any other source is replaced by an error
+/// carrying only its rendered message, since arbitrary errors are not `Clone`.
+impl Clone for ErrorX {
+    fn clone(&self) -> Self {
+        let source = self
+            .source
+            .as_deref()
+            .map(|source| match source.downcast_ref::<ErrorX>() {
+                Some(inner) => Box::new(inner.clone()) as Box<dyn Error + Send + Sync>,
+                None => Box::new(SourceMessage(source.to_string())),
+            });
+        ErrorX {
+            message: self.message.clone(),
+            backtrace: match self.backtrace.status() {
+                BacktraceStatus::Disabled => Backtrace::disabled(),
+                _ => Backtrace::force_capture(),
+            },
+            location: self.location,
+            context: self.context.clone(),
+            context_kv: self.context_kv.clone(),
+            secret_context: self.secret_context.clone(),
+            source,
+            status_code: self.status_code,
+            status: self.status.clone(),
+            severity: self.severity,
+            code: self.code.clone(),
+            retryable: self.retryable,
+            children: self.children.clone(),
+            timestamp: self.timestamp,
+            request_id: self.request_id.clone(),
+            extensions: self.extensions.clone(),
+        }
+    }
+}
+
 /// Builder for constructing ErrorX instances with a fluent API
 ///
 /// # Fields
 /// * `message` - The error message string
-/// * `context` - Vector of contextual information strings
+/// * `context` - Vector of contextual information entries, possibly computed lazily
+/// * `context_kv` - Vector of key-value contextual information pairs
+/// * `secret_context` - Vector of key-value pairs whose values are redacted when rendered
 /// * `location` - The source code location where builder was created
 /// * `source` - Optional underlying error that caused this error
 /// * `status_code` - Optional HTTP status code
 /// * `status` - Optional status message string
+/// * `severity` - Optional severity level
+/// * `code` - Optional stable, machine-readable error code
+/// * `retryable` - Optional explicit hint on whether the failed operation is worth retrying
+/// * `request_id` - Optional id of the request during which the error occurred
+/// * `capture_backtrace` - Whether a backtrace is captured on build
+/// * `backtrace_on_source_only` - Whether capture is further limited to errors with a source
+/// * `extensions` - Typed values attached for later retrieval
 #[derive(Debug)]
 pub struct ErrorXBuilder {
     message: String,
-    context: Vec<String>,
+    context: Vec<PendingContext>,
+    context_kv: Vec<(String, String)>,
+    secret_context: Vec<(String, Secret)>,
     location: &'static Location<'static>,
     source: Option<Box<dyn Error + Send + Sync>>,
     status_code: Option<u32>,
     status: Option<String>,
+    severity: Option<Severity>,
+    code: Option<String>,
+    retryable: Option<bool>,
+    request_id: Option<String>,
+    capture_backtrace: bool,
+    backtrace_on_source_only: bool,
+    extensions: Extensions,
 }
 
 impl ErrorXBuilder {
@@ -101,11 +324,20 @@ impl ErrorXBuilder {
     pub fn init(message: impl Into<String>) -> Self {
         Self {
             message: message.into(),
-            context: Vec::<String>::new(),
+            context: Vec::<PendingContext>::new(),
+            context_kv: Vec::<(String, String)>::new(),
+            secret_context: Vec::<(String, Secret)>::new(),
             location: Location::caller(),
             source: None,
             status_code: None,
             status: None,
+            severity: None,
+            code: None,
+            retryable: None,
+            request_id: None,
+            capture_backtrace: true,
+            backtrace_on_source_only: false,
+            extensions: Extensions::default(),
         }
     }
 
@@ -117,7 +349,109 @@ impl ErrorXBuilder {
     /// # Returns
     /// * `Self` - The builder instance for chaining
     pub fn with_context(mut self, context: impl Into<String>) -> Self {
-        self.context.push(context.into());
+        self.context.push(PendingContext::Ready(context.into()));
+        self
+    }
+
+    /// Adds several context entries to the error at once, preserving their order
+    ///
+    /// # Parameters
+    /// * `items` - The context strings to add
+    ///
+    /// # Returns
+    /// * `Self` - The builder instance for chaining
+    pub fn with_contexts(mut self, items: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.context.extend(
+            items
+                .into_iter()
+                .map(|item| PendingContext::Ready(item.into())),
+        );
+        self
+    }
+
+    /// Adds context information computed by a closure when the error is built
+    ///
+    /// The closure is invoked exactly once, during [`build`](Self::build), and its result keeps
+    /// its position relative to other context entries.
+    ///
+    /// # Parameters
+    /// * `f` - The closure producing the context string
+    ///
+    /// # Returns
+    /// * `Self` - The builder instance for chaining
+    pub fn with_context_lazy(mut self, f: impl FnOnce() -> String + Send + Sync + 'static) -> Self {
+        self.context.push(PendingContext::Lazy(Box::new(f)));
+        self
+    }
+
+    /// Adds context information only when the condition holds
+    ///
+    /// # Parameters
+    /// * `cond` - Whether to add the context
+    /// * `context` - The context string to add
+    ///
+    /// # Returns
+    /// * `Self` - The builder instance for chaining
+    pub fn with_context_if(self, cond: bool, context: impl Into<String>) -> Self {
+        if cond {
+            self.with_context(context)
+        } else {
+            self
+        }
+    }
+
+    /// Adds key-value context information to the error
+    ///
+    /// # Parameters
+    /// * `key` - The context key
+    /// * `value` - The context value
+    ///
+    /// # Returns
+    /// * `Self` - The builder instance for chaining
+    pub fn with_context_kv(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.context_kv.push((key.into(), value.into()));
+        self
+    }
+
+    /// Adds sensitive key-value context information to the error
+    ///
+    /// The value is stored but rendered as `key=***` in `Display`, `Debug` and serialized
+    /// output. It can still be read through [`ErrorX::secret_context`].
+    ///
+    /// # Parameters
+    /// * `key` - The context key
+    /// * `value` - The sensitive context value
+    ///
+    /// # Returns
+    /// * `Self` - The builder instance for chaining
+    pub fn with_secret_context(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.secret_context.push((key.into(), Secret(value.into())));
+        self
+    }
+
+    /// Copies the context of another error into this one
+    ///
+    /// Each context entry of `inner` is added as `inner: <entry>`, and each key-value pair as
+    /// `inner.<key>`, so the inherited entries stay distinguishable from this error's own.
+    /// Wrapping repeatedly stacks the prefix, one level per inherited error. Secret context is
+    /// not inherited.
+    ///
+    /// # Parameters
+    /// * `inner` - The error whose context to inherit
+    ///
+    /// # Returns
+    /// * `Self` - The builder instance for chaining
+    pub fn with_inherited_context(mut self, inner: &ErrorX) -> Self {
+        self.context.extend(inner.context.iter().map(|context| {
+            PendingContext::Ready(format!("{}: {}", INHERITED_CONTEXT_PREFIX, context))
+        }));
+        self.context_kv
+            .extend(inner.context_kv.iter().map(|(key, value)| {
+                (
+                    format!("{}.{}", INHERITED_CONTEXT_PREFIX, key),
+                    value.clone(),
+                )
+            }));
         self
     }
 
@@ -133,6 +467,20 @@ impl ErrorXBuilder {
         self
     }
 
+    /// Sets a `Display` value that is not an `Error` as the source
+    ///
+    /// The value is rendered with `to_string()` and stored as a synthetic error, so it appears in
+    /// the source chain like any other source.
+    ///
+    /// # Parameters
+    /// * `value` - The value describing the underlying failure
+    ///
+    /// # Returns
+    /// * `Self` - The builder instance for chaining
+    pub fn with_source_display(self, value: impl Display) -> Self {
+        self.with_source(SourceMessage(value.to_string()))
+    }
+
     /// Sets an HTTP-style status code for the error
     ///
     /// # Parameters
@@ -157,24 +505,165 @@ impl ErrorXBuilder {
         self
     }
 
+    /// Sets the severity level of the error
+    ///
+    /// # Parameters
+    /// * `severity` - The severity level to set
+    ///
+    /// # Returns
+    /// * `Self` - The builder instance for chaining
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    /// Sets a stable, machine-readable code for the error
+    ///
+    /// Codes such as `"AUTH_EXPIRED"` let clients branch on error types without parsing
+    /// messages, complementing the HTTP-style status code.
+    ///
+    /// # Parameters
+    /// * `code` - The error code to set
+    ///
+    /// # Returns
+    /// * `Self` - The builder instance for chaining
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// Sets whether the failed operation is worth retrying
+    ///
+    /// This overrides the default derived from the status code, see [`ErrorX::is_retryable`].
+    ///
+    /// # Parameters
+    /// * `retryable` - Whether the operation should be retried
+    ///
+    /// # Returns
+    /// * `Self` - The builder instance for chaining
+    pub fn with_retryable(mut self, retryable: bool) -> Self {
+        self.retryable = Some(retryable);
+        self
+    }
+
+    /// Sets the id of the request during which the error occurred
+    ///
+    /// Unlike free-form context, the request id is kept in a dedicated field so logs and
+    /// responses can be correlated by it.
+    ///
+    /// # Parameters
+    /// * `id` - The request id, e.g. taken from an `x-request-id` header or the current span
+    ///
+    /// # Returns
+    /// * `Self` - The builder instance for chaining
+    pub fn with_request_id(mut self, id: impl Into<String>) -> Self {
+        self.request_id = Some(id.into());
+        self
+    }
+
+    /// Overrides the location captured when the builder was created
+    ///
+    /// Helpers that build errors on behalf of their caller can mark themselves
+    /// `#[track_caller]` and forward `Location::caller()`, so the error points at the real call
+    /// site rather than the helper.
+    ///
+    /// # Parameters
+    /// * `location` - The source location to record
+    ///
+    /// # Returns
+    /// * `Self` - The builder instance for chaining
+    pub fn with_location(mut self, location: &'static Location<'static>) -> Self {
+        self.location = location;
+        self
+    }
+
+    /// Attaches a typed value to the error for later retrieval
+    ///
+    /// At most one value is stored per type, so attaching a second value of the same type
+    /// replaces the first. Extensions are not rendered in `Display` or serialized output.
+    ///
+    /// # Parameters
+    /// * `value` - The value to attach, such as a domain-specific error enum
+    ///
+    /// # Returns
+    /// * `Self` - The builder instance for chaining
+    pub fn with_extension<T: Send + Sync + 'static>(mut self, value: T) -> Self {
+        self.extensions.insert(value);
+        self
+    }
+
+    /// Enables or disables backtrace capture for the error
+    ///
+    /// Backtraces are captured by default. Capturing is expensive, so hot paths that create
+    /// errors frequently can opt out, in which case a disabled backtrace is stored. With the
+    /// `no-backtrace` feature, backtraces are never captured and this setting has no effect.
+    ///
+    /// # Parameters
+    /// * `enabled` - Whether to capture a backtrace on build
+    ///
+    /// # Returns
+    /// * `Self` - The builder instance for chaining
+    pub fn with_backtrace(mut self, enabled: bool) -> Self {
+        self.capture_backtrace = enabled;
+        self
+    }
+
+    /// Captures a backtrace only if the error has a source
+    ///
+    /// Bare sentinel errors are cheap to create and rarely need a backtrace, while errors
+    /// wrapping a source usually do. With this set, a backtrace is captured on build if a source
+    /// was set through [`ErrorXBuilder::with_source`] or [`ErrorXBuilder::with_source_display`],
+    /// in any order, and a disabled backtrace is stored otherwise. It never enables capture
+    /// turned off through [`ErrorXBuilder::with_backtrace`] or the `no-backtrace` feature.
+    ///
+    /// # Returns
+    /// * `Self` - The builder instance for chaining
+    pub fn capture_backtrace_on_source(mut self) -> Self {
+        self.backtrace_on_source_only = true;
+        self
+    }
+
     /// Builds and returns the ErrorX instance
     ///
     /// # Returns
     /// * `ErrorX` - The constructed error instance
     pub fn build(self) -> ErrorX {
+        let capture_backtrace = self.capture_backtrace
+            && (!self.backtrace_on_source_only || self.source.is_some())
+            && !cfg!(feature = "no-backtrace");
         ErrorX {
             message: self.message,
-            context: self.context,
+            context: self
+                .context
+                .into_iter()
+                .map(PendingContext::resolve)
+                .collect(),
+            context_kv: self.context_kv,
+            secret_context: self.secret_context,
             location: self.location,
-            backtrace: Backtrace::force_capture(),
+            backtrace: if capture_backtrace {
+                Backtrace::force_capture()
+            } else {
+                Backtrace::disabled()
+            },
             source: self.source,
             status_code: self.status_code,
             status: self.status,
+            severity: self.severity,
+            code: self.code,
+            retryable: self.retryable,
+            children: Vec::new(),
+            timestamp: SystemTime::now(),
+            request_id: self.request_id,
+            extensions: self.extensions,
         }
     }
 }
 
 impl ErrorX {
+    /// The default separator for [`ErrorX::context_breadcrumb`]
+    pub const BREADCRUMB_SEPARATOR: &'static str = " > ";
+
     /// Creates a new ErrorX with just a message
     ///
     /// # Parameters
@@ -199,6 +688,47 @@ impl ErrorX {
         ErrorXBuilder::init(message)
     }
 
+    /// Creates a new ErrorX wrapping a source error
+    ///
+    /// Shortcut for `ErrorX::builder(message).with_source(source).build()`. The location of the
+    /// caller is captured as the error location.
+    ///
+    /// # Parameters
+    /// * `source` - The underlying error that caused this error
+    /// * `message` - The error message
+    ///
+    /// # Returns
+    /// * `Self` - A new ErrorX instance with the source set
+    #[track_caller]
+    pub fn wrap(source: impl Error + Send + Sync + 'static, message: impl Into<String>) -> Self {
+        ErrorXBuilder::init(message).with_source(source).build()
+    }
+
+    /// Groups several independent errors into a single ErrorX
+    ///
+    /// The aggregate's message counts the grouped errors and its `Display` output lists each
+    /// of their messages. Its status code is the highest status code among the children, so a
+    /// server error outranks a client error. The location of the caller is captured as the
+    /// aggregate's location.
+    ///
+    /// # Parameters
+    /// * `errors` - The errors to group, kept in order
+    ///
+    /// # Returns
+    /// * `Self` - A new ErrorX holding the errors as children
+    #[track_caller]
+    pub fn aggregate(errors: Vec<ErrorX>) -> Self {
+        let message = match errors.len() {
+            1 => "1 error occurred".to_string(),
+            n => format!("{} errors occurred", n),
+        };
+        let mut builder = ErrorXBuilder::init(message);
+        builder.status_code = errors.iter().filter_map(|err| err.status_code).max();
+        let mut err = builder.build();
+        err.children = errors;
+        err
+    }
+
     /// Returns the error message
     ///
     /// # Returns
@@ -215,6 +745,54 @@ impl ErrorX {
         &self.context
     }
 
+    /// Checks whether any context entry contains a substring
+    ///
+    /// Only the plain context entries are searched, not key-value or secret context.
+    ///
+    /// # Parameters
+    /// * `substr` - The substring to look for
+    ///
+    /// # Returns
+    /// * `bool` - `true` if at least one context entry contains `substr`
+    pub fn context_contains(&self, substr: &str) -> bool {
+        self.context.iter().any(|context| context.contains(substr))
+    }
+
+    /// Joins the context entries into a single breadcrumb string
+    ///
+    /// Unlike `Display`, which joins context with commas and includes key-value pairs, this
+    /// only joins the plain context entries, in the order they were added. Pass
+    /// [`ErrorX::BREADCRUMB_SEPARATOR`] for the conventional `handler > service > db` form.
+    ///
+    /// # Parameters
+    /// * `sep` - The separator placed between entries
+    ///
+    /// # Returns
+    /// * `String` - The joined context, empty if there is none
+    pub fn context_breadcrumb(&self, sep: &str) -> String {
+        self.context.join(sep)
+    }
+
+    /// Returns the key-value context information
+    ///
+    /// # Returns
+    /// * `&Vec<(String, String)>` - Reference to the key-value context pairs
+    pub fn context_kv(&self) -> &Vec<(String, String)> {
+        &self.context_kv
+    }
+
+    /// Returns the unredacted secret context information
+    ///
+    /// Only use this in code paths authorized to see sensitive values.
+    ///
+    /// # Returns
+    /// * `impl Iterator<Item = (&str, &str)>` - Iterator over the secret key-value pairs
+    pub fn secret_context(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.secret_context
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.0.as_str()))
+    }
+
     /// Returns the location where the error was created
     ///
     /// # Returns
@@ -231,6 +809,33 @@ impl ErrorX {
         &self.backtrace
     }
 
+    /// Returns the attached extension of type `T`, if any
+    ///
+    /// # Returns
+    /// * `Option<&T>` - The value attached with [`ErrorXBuilder::with_extension`]
+    pub fn extension<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.extensions.get::<T>()
+    }
+
+    /// Returns the direct source error as a concrete type, if it is one
+    ///
+    /// # Returns
+    /// * `Option<&T>` - The source downcast to `T`, or `None` if unset or of another type
+    pub fn source_downcast_ref<T: Error + 'static>(&self) -> Option<&T> {
+        self.source.as_deref()?.downcast_ref::<T>()
+    }
+
+    /// Returns the first error of a concrete type in the source chain
+    ///
+    /// The chain is walked from the direct source outwards, as described in [`ErrorX::chain`].
+    /// This error itself is not considered.
+    ///
+    /// # Returns
+    /// * `Option<&T>` - The first source of type `T`, or `None` if there is none
+    pub fn find_source<T: Error + 'static>(&self) -> Option<&T> {
+        self.chain().skip(1).find_map(|err| err.downcast_ref::<T>())
+    }
+
     /// Returns the status code if set
     ///
     /// # Returns
@@ -246,6 +851,191 @@ impl ErrorX {
     pub fn status(&self) -> &Option<String> {
         &self.status
     }
+
+    /// Returns the severity level if set
+    ///
+    /// # Returns
+    /// * `Option<Severity>` - The optional severity level
+    pub fn severity(&self) -> Option<Severity> {
+        self.severity
+    }
+
+    /// Returns the error code if set
+    ///
+    /// # Returns
+    /// * `&Option<String>` - Reference to the optional error code
+    pub fn code(&self) -> &Option<String> {
+        &self.code
+    }
+
+    /// Returns whether two errors are semantically equal
+    ///
+    /// Compares the message, context, status code and status. The backtrace, location and
+    /// source are ignored, so an error equals its clone and errors created at different sites.
+    ///
+    /// # Parameters
+    /// * `other` - The error to compare with
+    ///
+    /// # Returns
+    /// * `bool` - Whether the errors are equal
+    pub fn same_as(&self, other: &ErrorX) -> bool {
+        self.message == other.message
+            && self.context == other.context
+            && self.status_code == other.status_code
+            && self.status == other.status
+    }
+
+    /// Returns the time at which the error was built
+    ///
+    /// # Returns
+    /// * `SystemTime` - The creation time of the error
+    pub fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
+
+    /// Returns the request id if set
+    ///
+    /// # Returns
+    /// * `&Option<String>` - Reference to the optional request id
+    pub fn request_id(&self) -> &Option<String> {
+        &self.request_id
+    }
+
+    /// Returns the errors grouped under this one
+    ///
+    /// # Returns
+    /// * `&[ErrorX]` - The child errors, empty unless created with [`ErrorX::aggregate`]
+    pub fn children(&self) -> &[ErrorX] {
+        &self.children
+    }
+
+    /// Returns the status code if it lies within the valid HTTP range of 100 to 599
+    fn http_status_code(&self) -> Option<u32> {
+        self.status_code.filter(|code| (100..=599).contains(code))
+    }
+
+    /// Returns whether the status code is an HTTP success code (2xx)
+    ///
+    /// # Returns
+    /// * `bool` - `true` for codes 200 to 299, `false` when unset or out of range
+    pub fn is_success(&self) -> bool {
+        matches!(self.http_status_code(), Some(200..=299))
+    }
+
+    /// Returns whether the status code is an HTTP client error code (4xx)
+    ///
+    /// # Returns
+    /// * `bool` - `true` for codes 400 to 499, `false` when unset or out of range
+    pub fn is_client_error(&self) -> bool {
+        matches!(self.http_status_code(), Some(400..=499))
+    }
+
+    /// Returns whether the status code is an HTTP server error code (5xx)
+    ///
+    /// # Returns
+    /// * `bool` - `true` for codes 500 to 599, `false` when unset or out of range
+    pub fn is_server_error(&self) -> bool {
+        matches!(self.http_status_code(), Some(500..=599))
+    }
+
+    /// Returns whether the failed operation is worth retrying
+    ///
+    /// An explicit value set with [`ErrorXBuilder::with_retryable`] always wins. Otherwise
+    /// server errors (5xx) are considered retryable and everything else is not.
+    ///
+    /// # Returns
+    /// * `bool` - Whether the operation should be retried
+    pub fn is_retryable(&self) -> bool {
+        self.retryable.unwrap_or_else(|| self.is_server_error())
+    }
+
+    /// Joins plain, key-value and redacted secret context into one comma-separated string
+    fn context_info(&self) -> String {
+        self.context
+            .iter()
+            .cloned()
+            .chain(self.context_kv.iter().map(|(k, v)| format!("{}={}", k, v)))
+            .chain(
+                self.secret_context
+                    .iter()
+                    .map(|(k, _)| format!("{}={}", k, REDACTED)),
+            )
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Renders the error and its whole source chain as a multi-section report
+    ///
+    /// Each level of the chain is numbered, starting at `0` for this error. Levels that are
+    /// themselves ErrorX values list their message, location and context, while other errors
+    /// are rendered with their `Display` output. The backtrace of this error is appended once
+    /// at the end. Unlike `Display`, the report is meant for attaching to tickets and logs.
+    ///
+    /// # Returns
+    /// * `String` - The rendered report
+    pub fn report(&self) -> String {
+        self.render_report(&ReportStyle::PLAIN)
+    }
+
+    /// Renders the report described in [`ErrorX::report`] with the given style
+    fn render_report(&self, style: &ReportStyle) -> String {
+        use std::fmt::Write;
+
+        let ReportStyle {
+            message,
+            location,
+            context,
+            reset,
+        } = style;
+        let mut report = String::new();
+        for (level, err) in self.chain().enumerate() {
+            match err.downcast_ref::<ErrorX>() {
+                Some(err) => {
+                    let _ = writeln!(report, "{}[{}] {}{}", message, level, err.message, reset);
+                    let _ = writeln!(
+                        report,
+                        "    {}Location: {}:{}{}",
+                        location,
+                        err.location.file(),
+                        err.location.line(),
+                        reset
+                    );
+                    let context_info = err.context_info();
+                    if !context_info.is_empty() {
+                        let _ =
+                            writeln!(report, "    {}Context: {}{}", context, context_info, reset);
+                    }
+                }
+                None => {
+                    let _ = writeln!(report, "{}[{}] {}{}", message, level, err, reset);
+                }
+            }
+        }
+        let _ = write!(report, "Backtrace:\n{}", self.backtrace);
+        report
+    }
+
+    /// Returns an iterator over this error and its chain of sources
+    ///
+    /// The iterator yields `self` first and then follows each `source()` link. Iteration is
+    /// bounded so that a malformed, cyclic chain cannot loop forever.
+    ///
+    /// # Returns
+    /// * `impl Iterator<Item = &(dyn Error + 'static)>` - Iterator over the error chain
+    pub fn chain(&self) -> impl Iterator<Item = &(dyn Error + 'static)> {
+        std::iter::successors(Some(self as &(dyn Error + 'static)), |&err| err.source())
+            .take(MAX_CHAIN_DEPTH)
+    }
+
+    /// Returns the deepest error in the source chain
+    ///
+    /// This is the last item yielded by [`ErrorX::chain`], usually the originating failure.
+    ///
+    /// # Returns
+    /// * `&(dyn Error + 'static)` - The innermost source, or `self` if there is no source
+    pub fn root_cause(&self) -> &(dyn Error + 'static) {
+        self.chain().last().unwrap_or(self)
+    }
 }
 
 #[cfg(test)]
@@ -293,16 +1083,157 @@ mod tests {
             .with_status_code(500)
             .with_status("Internal Server Error")
             .build();
-        let err_string = format!("{}", err);
+        let err_string = format!("{:#}", err);
         assert!(err_string.contains("Failed to process file"));
         assert!(err_string.contains("errorsx/mod.rs"));
         assert!(err_string.contains("Context:"));
         assert!(err_string.contains("Source"));
     }
 
+    #[test]
+    fn test_errorsx_display_compact() {
+        let err = ErrorX::builder("Failed to process file")
+            .with_context("Processing user upload")
+            .with_context("Validating file")
+            .build();
+        let err_string = format!("{}", err);
+        assert!(!err_string.contains('\n'));
+        assert!(err_string.contains("Failed to process file"));
+        assert!(err_string.contains("errorsx/mod.rs"));
+        assert!(err_string.contains("Processing user upload,Validating file"));
+        assert!(!err_string.contains("Source"));
+    }
+
+    #[test]
+    fn test_errorx_secret_context() {
+        let err = ErrorX::builder("Login failed")
+            .with_context("Authenticating user")
+            .with_secret_context("email", "jane@example.com")
+            .build();
+
+        let display = format!("{}", err);
+        assert!(display.contains("email=***"));
+        assert!(!display.contains("jane@example.com"));
+        assert!(!format!("{:#}", err).contains("jane@example.com"));
+        assert!(!format!("{:?}", err).contains("jane@example.com"));
+        assert_eq!(
+            err.secret_context().collect::<Vec<_>>(),
+            vec![("email", "jane@example.com")]
+        );
+    }
+
+    #[test]
+    fn test_errorx_severity() {
+        let err = ErrorX::new("Unclassified");
+        assert_eq!(err.severity(), None);
+        assert!(!format!("{}", err).contains("Severity"));
+
+        let err = ErrorX::builder("Disk almost full")
+            .with_severity(Severity::Critical)
+            .build();
+        assert_eq!(err.severity(), Some(Severity::Critical));
+        assert!(err.severity() >= Some(Severity::Error));
+        assert!(format!("{}", err).contains("Severity: CRITICAL"));
+    }
+
+    #[test]
+    fn test_errorx_code() {
+        let err = ErrorX::new("No Code");
+        assert!(err.code().is_none());
+
+        let err = ErrorX::builder("Session expired")
+            .with_code("AUTH_EXPIRED")
+            .with_status_code(401)
+            .build();
+        assert_eq!(err.code(), &Some("AUTH_EXPIRED".to_string()));
+        assert!(format!("{}", err).contains("Code: AUTH_EXPIRED"));
+    }
+
+    #[test]
+    fn test_errorx_without_backtrace() {
+        use std::backtrace::BacktraceStatus;
+        use std::time::Instant;
+
+        let err = ErrorX::builder("No Backtrace")
+            .with_backtrace(false)
+            .build();
+        assert_eq!(err.backtrace().status(), BacktraceStatus::Disabled);
+
+        // Nothing is ever captured with the feature, so there is no cost difference to measure
+        if cfg!(feature = "no-backtrace") {
+            return;
+        }
+
+        let iterations = 200;
+        let start = Instant::now();
+        for _ in 0..iterations {
+            ErrorX::builder("Captured").build();
+        }
+        let captured = start.elapsed();
+
+        let start = Instant::now();
+        for _ in 0..iterations {
+            ErrorX::builder("Not Captured")
+                .with_backtrace(false)
+                .build();
+        }
+        let not_captured = start.elapsed();
+
+        assert!(not_captured < captured);
+    }
+
+    #[test]
+    fn test_errorx_context_lazy() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let builder = ErrorX::builder("Lazy Context")
+            .with_context("first")
+            .with_context_lazy(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                "second".to_string()
+            })
+            .with_context("third");
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        let err = builder.build();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(err.context(), &vec!["first", "second", "third"]);
+    }
+
+    #[test]
+    fn test_errorx_context_if() {
+        let err = ErrorX::builder("Conditional Context")
+            .with_context_if(true, "kept")
+            .with_context_if(false, "dropped")
+            .build();
+        assert_eq!(err.context(), &vec!["kept".to_string()]);
+    }
+
+    #[test]
+    fn test_errorx_context_kv() {
+        let err = ErrorX::builder("Failed to load user")
+            .with_context("Loading profile")
+            .with_context_kv("user_id", "42")
+            .with_context_kv("region", "eu")
+            .build();
+
+        assert_eq!(err.context(), &vec!["Loading profile".to_string()]);
+        assert_eq!(
+            err.context_kv(),
+            &vec![
+                ("user_id".to_string(), "42".to_string()),
+                ("region".to_string(), "eu".to_string())
+            ]
+        );
+        assert!(format!("{}", err).contains("Loading profile,user_id=42,region=eu"));
+    }
+
     #[test]
     fn test_errorx_source() {
-        let io_error = io::Error::new(io::ErrorKind::Other, "IO Error");
+        let io_error = io::Error::other("IO Error");
         let err = ErrorX::builder("Higher Level Error")
             .with_source(io_error)
             .build();
@@ -310,4 +1241,465 @@ mod tests {
         assert!(err.source().is_some());
         assert_eq!(err.source().unwrap().to_string(), "IO Error");
     }
+
+    #[test]
+    fn test_errorx_chain() {
+        let io_error = io::Error::other("IO Error");
+        let inner = ErrorX::builder("Inner Error").with_source(io_error).build();
+        let err = ErrorX::builder("Outer Error").with_source(inner).build();
+
+        let messages: Vec<String> = err
+            .chain()
+            .skip(1)
+            .map(|e| match e.downcast_ref::<ErrorX>() {
+                Some(x) => x.message().to_string(),
+                None => e.to_string(),
+            })
+            .collect();
+        assert_eq!(err.chain().count(), 3);
+        assert_eq!(messages, vec!["Inner Error", "IO Error"]);
+    }
+
+    #[test]
+    fn test_status_code_predicates() {
+        let not_found = ErrorX::builder("Not found").with_status_code(404).build();
+        assert!(not_found.is_client_error());
+        assert!(!not_found.is_server_error());
+        assert!(!not_found.is_success());
+
+        let internal = ErrorX::builder("Internal").with_status_code(500).build();
+        assert!(internal.is_server_error());
+        assert!(!internal.is_client_error());
+
+        let accepted = ErrorX::builder("Accepted").with_status_code(202).build();
+        assert!(accepted.is_success());
+    }
+
+    #[test]
+    fn test_status_code_predicates_unset_or_invalid() {
+        for err in [
+            ErrorX::new("Unset"),
+            ErrorX::builder("Invalid").with_status_code(70000).build(),
+            ErrorX::builder("Invalid").with_status_code(42).build(),
+        ] {
+            assert!(!err.is_success());
+            assert!(!err.is_client_error());
+            assert!(!err.is_server_error());
+        }
+    }
+
+    #[test]
+    fn test_retryable_explicit() {
+        let err = ErrorX::builder("Timed out").with_retryable(true).build();
+        assert!(err.is_retryable());
+
+        let err = ErrorX::builder("Unavailable")
+            .with_status_code(503)
+            .with_retryable(false)
+            .build();
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn test_retryable_default_from_status_code() {
+        assert!(!ErrorX::new("Unset").is_retryable());
+        assert!(ErrorX::builder("Unavailable")
+            .with_status_code(503)
+            .build()
+            .is_retryable());
+        assert!(!ErrorX::builder("Not found")
+            .with_status_code(404)
+            .build()
+            .is_retryable());
+    }
+
+    #[test]
+    fn test_report() {
+        let io_error = io::Error::new(io::ErrorKind::NotFound, "File not found");
+        let inner = ErrorX::builder("Failed to read config")
+            .with_context("Loading settings")
+            .with_context_kv("path", "app.toml")
+            .with_source(io_error)
+            .build();
+        let err = ErrorX::builder("Failed to start server")
+            .with_source(inner)
+            .build();
+        let report = err.report();
+
+        assert!(report.contains("[0] Failed to start server"));
+        assert!(report.contains("[1] Failed to read config"));
+        assert!(report.contains("Context: Loading settings,path=app.toml"));
+        assert!(report.contains("[2] File not found"));
+        assert!(report.contains("Location: src/errorsx/mod.rs:"));
+        assert_eq!(report.matches("Backtrace:").count(), 1);
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let err = ErrorX::aggregate(vec![
+            ErrorX::builder("Name is required")
+                .with_status_code(400)
+                .build(),
+            ErrorX::builder("Email is invalid")
+                .with_status_code(422)
+                .build(),
+            ErrorX::new("Age must be positive"),
+        ]);
+
+        assert_eq!(err.message(), "3 errors occurred");
+        assert_eq!(err.children().len(), 3);
+        assert_eq!(err.status_code(), &Some(422));
+        assert_eq!(err.location().file(), "src/errorsx/mod.rs");
+
+        let err_string = err.to_string();
+        assert!(err_string.contains("Name is required"));
+        assert!(err_string.contains("Email is invalid"));
+        assert!(err_string.contains("Age must be positive"));
+    }
+
+    #[test]
+    fn test_aggregate_without_status_codes() {
+        let err = ErrorX::aggregate(vec![ErrorX::new("Only one")]);
+        assert_eq!(err.message(), "1 error occurred");
+        assert!(err.status_code().is_none());
+        assert!(ErrorX::new("Plain").children().is_empty());
+    }
+
+    #[test]
+    fn test_source_downcast_ref() {
+        let io_error = io::Error::new(io::ErrorKind::NotFound, "File not found");
+        let err = ErrorX::builder("Failed to read")
+            .with_source(io_error)
+            .build();
+
+        let source = err.source_downcast_ref::<io::Error>().unwrap();
+        assert_eq!(source.kind(), io::ErrorKind::NotFound);
+        assert!(err.source_downcast_ref::<std::fmt::Error>().is_none());
+        assert!(ErrorX::new("No source")
+            .source_downcast_ref::<io::Error>()
+            .is_none());
+    }
+
+    #[test]
+    fn test_find_source() {
+        let io_error = io::Error::new(io::ErrorKind::PermissionDenied, "Access denied");
+        let inner = ErrorX::builder("Inner Error").with_source(io_error).build();
+        let err = ErrorX::builder("Outer Error").with_source(inner).build();
+
+        assert!(err.source_downcast_ref::<io::Error>().is_none());
+        let found = err.find_source::<io::Error>().unwrap();
+        assert_eq!(found.kind(), io::ErrorKind::PermissionDenied);
+        assert_eq!(
+            err.find_source::<ErrorX>().unwrap().message(),
+            "Inner Error"
+        );
+        assert!(err.find_source::<std::fmt::Error>().is_none());
+    }
+
+    #[test]
+    fn test_same_as() {
+        let build = || {
+            ErrorX::builder("Not found")
+                .with_context("Loading user")
+                .with_status_code(404)
+                .with_status("Not Found")
+                .build()
+        };
+        assert!(build().same_as(&build()));
+        assert!(!build().same_as(&ErrorX::new("Not found")));
+        assert!(!build().same_as(
+            &ErrorX::builder("Not found")
+                .with_context("Loading user")
+                .with_status_code(410)
+                .with_status("Not Found")
+                .build()
+        ));
+    }
+
+    #[test]
+    fn test_clone() {
+        let io_error = io::Error::new(io::ErrorKind::NotFound, "File not found");
+        let inner = ErrorX::builder("Inner Error").with_source(io_error).build();
+        let err = ErrorX::builder("Outer Error")
+            .with_secret_context("token", "s3cr3t")
+            .with_code("OUTER")
+            .with_source(inner)
+            .build();
+        let cloned = err.clone();
+
+        assert!(cloned.same_as(&err));
+        assert_eq!(cloned.location(), err.location());
+        assert_eq!(cloned.code(), &Some("OUTER".to_string()));
+        assert_eq!(cloned.secret_context().next(), Some(("token", "s3cr3t")));
+        assert_eq!(
+            cloned.find_source::<ErrorX>().unwrap().message(),
+            "Inner Error"
+        );
+        // Non-ErrorX sources keep their message but lose their concrete type
+        assert!(cloned.find_source::<io::Error>().is_none());
+        assert_eq!(cloned.chain().last().unwrap().to_string(), "File not found");
+    }
+
+    #[test]
+    fn test_clone_keeps_backtrace_disabled() {
+        let err = ErrorX::builder("No backtrace")
+            .with_backtrace(false)
+            .build();
+        assert_eq!(err.clone().backtrace().status(), BacktraceStatus::Disabled);
+    }
+
+    #[test]
+    fn test_timestamp() {
+        use std::time::Duration;
+
+        let before = SystemTime::now();
+        let err = ErrorX::new("Timed");
+        let elapsed = err.timestamp().duration_since(before).unwrap();
+        assert!(elapsed < Duration::from_secs(1));
+        assert_eq!(err.clone().timestamp(), err.timestamp());
+        assert!(err.to_string().contains("Timestamp: "));
+        assert!(err.to_string().ends_with('Z'));
+    }
+
+    #[test]
+    fn test_with_contexts() {
+        let err = ErrorX::builder("Test Error")
+            .with_context("First")
+            .with_contexts(["Second", "Third", "Fourth"])
+            .with_contexts(vec!["Fifth".to_string()])
+            .build();
+        assert_eq!(
+            err.context(),
+            &vec![
+                "First".to_string(),
+                "Second".to_string(),
+                "Third".to_string(),
+                "Fourth".to_string(),
+                "Fifth".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_with_source_display() {
+        enum Reason {
+            QuotaExceeded,
+        }
+
+        impl Display for Reason {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    Reason::QuotaExceeded => f.write_str("quota exceeded"),
+                }
+            }
+        }
+
+        let err = ErrorX::builder("Upload rejected")
+            .with_source_display(Reason::QuotaExceeded)
+            .build();
+        assert_eq!(err.source().unwrap().to_string(), "quota exceeded");
+        assert_eq!(err.chain().count(), 2);
+    }
+
+    #[test]
+    fn test_with_location() {
+        #[track_caller]
+        fn not_found(resource: &str) -> ErrorX {
+            let caller = Location::caller();
+            build_not_found(resource, caller)
+        }
+
+        fn build_not_found(resource: &str, caller: &'static Location<'static>) -> ErrorX {
+            ErrorX::builder(format!("{} not found", resource))
+                .with_status_code(404)
+                .with_location(caller)
+                .build()
+        }
+
+        let err = not_found("User");
+        assert_eq!(err.location().file(), "src/errorsx/mod.rs");
+        assert_eq!(err.location().line(), line!() - 2);
+        assert_eq!(err.message(), "User not found");
+    }
+
+    #[cfg(feature = "no-backtrace")]
+    #[test]
+    fn test_no_backtrace_feature() {
+        let err = ErrorX::builder("No backtrace").with_backtrace(true).build();
+        assert_eq!(err.backtrace().status(), BacktraceStatus::Disabled);
+        assert_eq!(
+            ErrorX::new("Cloned").clone().backtrace().status(),
+            BacktraceStatus::Disabled
+        );
+    }
+
+    #[test]
+    fn test_request_id() {
+        let err = ErrorX::builder("Upstream timed out")
+            .with_request_id("req-7f3a")
+            .build();
+        assert_eq!(err.request_id(), &Some("req-7f3a".to_string()));
+        assert!(err.context().is_empty());
+        assert!(err.to_string().contains("Request ID: req-7f3a"));
+        assert_eq!(err.clone().request_id(), err.request_id());
+        assert!(ErrorX::new("No request").request_id().is_none());
+    }
+
+    #[test]
+    fn test_with_inherited_context() {
+        let inner = ErrorX::builder("Query failed")
+            .with_context("Running user lookup")
+            .with_context_kv("table", "users")
+            .build();
+        let outer = ErrorX::builder("Failed to load profile")
+            .with_context("Handling GET /profile")
+            .with_inherited_context(&inner)
+            .build();
+
+        assert_eq!(
+            outer.context(),
+            &vec![
+                "Handling GET /profile".to_string(),
+                "inner: Running user lookup".to_string()
+            ]
+        );
+        assert_eq!(
+            outer.context_kv(),
+            &vec![("inner.table".to_string(), "users".to_string())]
+        );
+
+        let outermost = ErrorX::builder("Request failed")
+            .with_inherited_context(&outer)
+            .build();
+        assert_eq!(outermost.context()[1], "inner: inner: Running user lookup");
+    }
+
+    #[test]
+    fn test_capture_backtrace_on_source_without_source() {
+        let err = ErrorX::builder("Sentinel")
+            .capture_backtrace_on_source()
+            .build();
+        assert_eq!(err.backtrace().status(), BacktraceStatus::Disabled);
+    }
+
+    #[test]
+    fn test_capture_backtrace_on_source_with_source() {
+        let expected = if cfg!(feature = "no-backtrace") {
+            BacktraceStatus::Disabled
+        } else {
+            BacktraceStatus::Captured
+        };
+
+        let err = ErrorX::builder("Wrapped")
+            .capture_backtrace_on_source()
+            .with_source(io::Error::other("IO Error"))
+            .build();
+        assert_eq!(err.backtrace().status(), expected);
+
+        let err = ErrorX::builder("Wrapped")
+            .with_source_display("timed out")
+            .capture_backtrace_on_source()
+            .with_backtrace(false)
+            .build();
+        assert_eq!(err.backtrace().status(), BacktraceStatus::Disabled);
+    }
+
+    #[test]
+    fn test_root_cause() {
+        let io_error = io::Error::new(io::ErrorKind::NotFound, "File not found");
+        let inner = ErrorX::builder("Read failed").with_source(io_error).build();
+        let middle = ErrorX::builder("Load failed").with_source(inner).build();
+        let outer = ErrorX::builder("Startup failed")
+            .with_source(middle)
+            .build();
+
+        let root = outer.root_cause();
+        assert_eq!(root.to_string(), "File not found");
+        assert_eq!(
+            root.downcast_ref::<io::Error>().map(io::Error::kind),
+            Some(io::ErrorKind::NotFound)
+        );
+
+        let bare = ErrorX::new("Bare");
+        assert_eq!(
+            bare.root_cause()
+                .downcast_ref::<ErrorX>()
+                .unwrap()
+                .message(),
+            "Bare"
+        );
+    }
+
+    #[test]
+    fn test_wrap() {
+        let io_error = io::Error::new(io::ErrorKind::NotFound, "File not found");
+        let err = ErrorX::wrap(io_error, "Failed to load config");
+        let line = line!() - 1;
+
+        assert_eq!(err.message(), "Failed to load config");
+        assert_eq!(err.source().unwrap().to_string(), "File not found");
+        assert!(err.source_downcast_ref::<io::Error>().is_some());
+        assert_eq!(err.location().file(), file!());
+        assert_eq!(err.location().line(), line);
+    }
+
+    #[test]
+    fn test_context_breadcrumb() {
+        let err = ErrorX::builder("Query failed")
+            .with_context("handler")
+            .with_context("service")
+            .with_context("db")
+            .with_context_kv("table", "users")
+            .build();
+
+        assert_eq!(
+            err.context_breadcrumb(ErrorX::BREADCRUMB_SEPARATOR),
+            "handler > service > db"
+        );
+        assert_eq!(err.context_breadcrumb("/"), "handler/service/db");
+        assert_eq!(ErrorX::new("Bare").context_breadcrumb(" > "), "");
+    }
+
+    #[test]
+    fn test_extension() {
+        #[derive(Debug, PartialEq)]
+        struct Quota {
+            limit: u32,
+            used: u32,
+        }
+
+        let err = ErrorX::builder("Quota exceeded")
+            .with_extension(Quota {
+                limit: 10,
+                used: 12,
+            })
+            .with_extension("replaced")
+            .with_extension("tenant-7")
+            .build();
+
+        assert_eq!(
+            err.extension::<Quota>(),
+            Some(&Quota {
+                limit: 10,
+                used: 12
+            })
+        );
+        assert_eq!(err.extension::<&str>(), Some(&"tenant-7"));
+        assert!(err.extension::<u32>().is_none());
+        assert_eq!(err.clone().extension::<Quota>(), err.extension::<Quota>());
+    }
+
+    #[test]
+    fn test_context_contains() {
+        let err = ErrorX::builder("Failed to load user")
+            .with_context("Loading profile for user 42")
+            .with_context_kv("region", "eu")
+            .build();
+
+        assert!(err.context_contains("profile"));
+        assert!(err.context_contains("Loading profile for user 42"));
+        assert!(!err.context_contains("Profile"));
+        assert!(!err.context_contains("region"));
+        assert!(!ErrorX::new("Bare").context_contains(""));
+    }
 }
@@ -0,0 +1,74 @@
+//! Process exit handling for [`ErrorX`]
+//!
+//! Lets command-line tools turn an error into a process exit code, or report it and exit in
+//! one step from `main`.
+//!
+//! # Example
+//! ```no_run
+//! # use crate::toolkit::errorsx::ErrorX;
+//!
+//! fn run() -> Result<(), ErrorX> {
+//!     Err(ErrorX::builder("Config file missing").with_status_code(2).build())
+//! }
+//!
+//! if let Err(err) = run() {
+//!     err.report_and_exit();
+//! }
+//! ```
+
+use super::ErrorX;
+
+/// Exit code used when no usable status code is set
+const DEFAULT_EXIT_CODE: i32 = 1;
+
+impl ErrorX {
+    /// Returns the process exit code for the error
+    ///
+    /// The status code is reduced modulo 256, since only the low byte of an exit code is seen
+    /// by the parent process on Unix. Exit code `0` signals success, so an unset status code or
+    /// one that reduces to `0` becomes `1`.
+    ///
+    /// # Returns
+    /// * `i32` - An exit code between 1 and 255
+    pub fn exit_code(&self) -> i32 {
+        match self.status_code.map(|code| (code % 256) as i32) {
+            Some(0) | None => DEFAULT_EXIT_CODE,
+            Some(code) => code,
+        }
+    }
+
+    /// Prints the error report to stderr and exits the process
+    ///
+    /// The report is the one produced by [`ErrorX::report`], and the process exits with
+    /// [`ErrorX::exit_code`]. Destructors of values on the stack are not run.
+    pub fn report_and_exit(self) -> ! {
+        eprintln!("{}", self.report());
+        std::process::exit(self.exit_code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_status_code(status_code: u32) -> ErrorX {
+        ErrorX::builder("Failed")
+            .with_status_code(status_code)
+            .build()
+    }
+
+    #[test]
+    fn test_exit_code() {
+        assert_eq!(with_status_code(2).exit_code(), 2);
+        assert_eq!(with_status_code(255).exit_code(), 255);
+        assert_eq!(with_status_code(404).exit_code(), 148);
+        assert_eq!(with_status_code(500).exit_code(), 244);
+    }
+
+    #[test]
+    fn test_exit_code_never_success() {
+        assert_eq!(ErrorX::new("Unset").exit_code(), 1);
+        assert_eq!(with_status_code(0).exit_code(), 1);
+        assert_eq!(with_status_code(256).exit_code(), 1);
+    }
+}
@@ -0,0 +1,85 @@
+//! Axum integration for [`ErrorX`]
+//!
+//! Available with the `axum` feature. [`ErrorX`] implements `IntoResponse` so it can be
+//! returned directly from handlers. The response status is taken from the error's status code,
+//! falling back to `500 Internal Server Error` when it is unset or not a valid HTTP status, and
+//! the body is a JSON object holding the `message`, `status` and `code`.
+//!
+//! # Example
+//! ```
+//! # use crate::toolkit::errorsx::ErrorX;
+//! use axum::response::IntoResponse;
+//!
+//! let err = ErrorX::builder("User not found")
+//!     .with_status_code(404)
+//!     .with_status("Not Found")
+//!     .build();
+//! let response = err.into_response();
+//! assert_eq!(response.status(), 404);
+//! ```
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::json;
+
+use super::ErrorX;
+
+impl IntoResponse for ErrorX {
+    fn into_response(self) -> Response {
+        let status_code = self
+            .status_code
+            .and_then(|code| u16::try_from(code).ok())
+            .and_then(|code| StatusCode::from_u16(code).ok())
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let body = json!({
+            "message": self.message,
+            "status": self.status,
+            "code": self.code,
+        });
+        (status_code, Json(body)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn body_json(response: Response) -> serde_json::Value {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_into_response() {
+        let err = ErrorX::builder("User not found")
+            .with_status_code(404)
+            .with_status("Not Found")
+            .with_code("USER_NOT_FOUND")
+            .build();
+        let response = err.into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let body = body_json(response).await;
+        assert_eq!(body["message"], "User not found");
+        assert_eq!(body["status"], "Not Found");
+        assert_eq!(body["code"], "USER_NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn test_into_response_fallback() {
+        let unset = ErrorX::new("Unexpected failure").into_response();
+        assert_eq!(unset.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(body_json(unset).await["status"].is_null());
+
+        let invalid = ErrorX::builder("Bad code")
+            .with_status_code(70000)
+            .build()
+            .into_response();
+        assert_eq!(invalid.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
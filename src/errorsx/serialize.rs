@@ -0,0 +1,163 @@
+//! Serde serialization support for [`ErrorX`]
+//!
+//! Available with the `serde` feature. An [`ErrorX`] serializes into a flat object with the
+//! following keys:
+//!
+//! - `message` - The error message
+//! - `context` - The list of context strings
+//! - `context_kv` - The key-value context pairs as an object, in insertion order
+//! - `secret_context` - The secret context keys as an object, with every value redacted
+//! - `location` - The creation site rendered as `file:line`
+//! - `status_code` - The optional status code
+//! - `status` - The optional status message
+//! - `severity` - The optional severity level
+//! - `code` - The optional machine-readable error code
+//! - `request_id` - The optional id of the request during which the error occurred
+//! - `retryable` - Whether the operation is worth retrying, see [`ErrorX::is_retryable`]
+//! - `source` - The source error, nested as an object when it is itself an [`ErrorX`] and
+//!   rendered with `to_string()` otherwise
+//! - `children` - The grouped errors of an aggregate, each nested as an object
+//! - `timestamp` - The creation time as an RFC 3339 UTC string
+//! - `backtrace` - The rendered backtrace, only present with the `include_backtrace` feature
+//!
+//! # Example
+//! ```
+//! # use crate::toolkit::errorsx::ErrorX;
+//!
+//! let err = ErrorX::builder("Failed to process file")
+//!     .with_status_code(500)
+//!     .build();
+//! let json = serde_json::to_string(&err).unwrap();
+//! assert!(json.contains("\"message\":\"Failed to process file\""));
+//! ```
+
+use std::error::Error;
+
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use super::{timestamp::format_rfc3339, ErrorX, Secret, REDACTED};
+
+/// Serializes key-value context pairs as a map, preserving insertion order
+struct ContextKvRepr<'e>(&'e [(String, String)]);
+
+impl Serialize for ContextKvRepr<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(k, v)| (k, v)))
+    }
+}
+
+/// Serializes secret context pairs as a map with redacted values
+struct SecretContextRepr<'e>(&'e [(String, Secret)]);
+
+impl Serialize for SecretContextRepr<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(k, _)| (k, REDACTED)))
+    }
+}
+
+/// Serializes a boxed source error, recursing into nested [`ErrorX`] values
+struct SourceRepr<'e>(&'e (dyn Error + Send + Sync + 'static));
+
+impl Serialize for SourceRepr<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0.downcast_ref::<ErrorX>() {
+            Some(inner) => inner.serialize(serializer),
+            None => serializer.serialize_str(&self.0.to_string()),
+        }
+    }
+}
+
+impl Serialize for ErrorX {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let field_count = if cfg!(feature = "include_backtrace") {
+            15
+        } else {
+            14
+        };
+        let location = format!("{}:{}", self.location.file(), self.location.line());
+
+        let mut state = serializer.serialize_struct("ErrorX", field_count)?;
+        state.serialize_field("message", &self.message)?;
+        state.serialize_field("context", &self.context)?;
+        state.serialize_field("context_kv", &ContextKvRepr(&self.context_kv))?;
+        state.serialize_field("secret_context", &SecretContextRepr(&self.secret_context))?;
+        state.serialize_field("location", &location)?;
+        state.serialize_field("status_code", &self.status_code)?;
+        state.serialize_field("status", &self.status)?;
+        state.serialize_field("severity", &self.severity)?;
+        state.serialize_field("code", &self.code)?;
+        state.serialize_field("request_id", &self.request_id)?;
+        state.serialize_field("retryable", &self.is_retryable())?;
+        state.serialize_field("source", &self.source.as_deref().map(SourceRepr))?;
+        state.serialize_field("children", &self.children)?;
+        state.serialize_field("timestamp", &format_rfc3339(self.timestamp))?;
+        #[cfg(feature = "include_backtrace")]
+        state.serialize_field("backtrace", &self.backtrace.to_string())?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errorsx::Severity;
+    use std::io;
+
+    #[test]
+    fn test_serialize_fields() {
+        let err = ErrorX::builder("Failed to process file")
+            .with_context("Processing user upload")
+            .with_context_kv("user_id", "42")
+            .with_secret_context("token", "s3cr3t")
+            .with_status_code(500)
+            .with_status("Internal Server Error")
+            .with_severity(Severity::Error)
+            .with_code("UPLOAD_FAILED")
+            .with_request_id("req-42")
+            .build();
+        let json: serde_json::Value = serde_json::to_value(&err).unwrap();
+
+        assert_eq!(json["message"], "Failed to process file");
+        assert_eq!(json["context"][0], "Processing user upload");
+        assert_eq!(json["context_kv"]["user_id"], "42");
+        assert_eq!(json["secret_context"]["token"], "***");
+        assert!(!json.to_string().contains("s3cr3t"));
+        assert_eq!(json["status_code"], 500);
+        assert_eq!(json["status"], "Internal Server Error");
+        assert_eq!(json["severity"], "Error");
+        assert_eq!(json["code"], "UPLOAD_FAILED");
+        assert_eq!(json["request_id"], "req-42");
+        assert_eq!(json["retryable"], true);
+        assert!(json["location"]
+            .as_str()
+            .unwrap()
+            .starts_with("src/errorsx/serialize.rs:"));
+        assert!(json["source"].is_null());
+        assert_eq!(json["children"], serde_json::json!([]));
+        assert!(json["timestamp"].as_str().unwrap().ends_with('Z'));
+        assert_eq!(
+            json.get("backtrace").is_some(),
+            cfg!(feature = "include_backtrace")
+        );
+    }
+
+    #[test]
+    fn test_serialize_source() {
+        let io_error = io::Error::new(io::ErrorKind::NotFound, "File not found");
+        let inner = ErrorX::builder("Inner Error").with_source(io_error).build();
+        let err = ErrorX::builder("Outer Error").with_source(inner).build();
+        let json: serde_json::Value = serde_json::to_value(&err).unwrap();
+
+        assert_eq!(json["source"]["message"], "Inner Error");
+        assert_eq!(json["source"]["source"], "File not found");
+    }
+
+    #[test]
+    fn test_serialize_children() {
+        let err = ErrorX::aggregate(vec![ErrorX::new("First"), ErrorX::new("Second")]);
+        let json: serde_json::Value = serde_json::to_value(&err).unwrap();
+
+        assert_eq!(json["children"][0]["message"], "First");
+        assert_eq!(json["children"][1]["message"], "Second");
+    }
+}
@@ -0,0 +1,51 @@
+//! Severity levels for classifying [`ErrorX`](super::ErrorX) values
+//!
+//! Levels are ordered from least to most severe, so they can be compared to route errors,
+//! e.g. `err.severity() >= Some(Severity::Error)`.
+
+use std::fmt::Display;
+
+/// Severity level of an error, ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Severity {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Critical,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let level = match self {
+            Severity::Trace => "TRACE",
+            Severity::Debug => "DEBUG",
+            Severity::Info => "INFO",
+            Severity::Warn => "WARN",
+            Severity::Error => "ERROR",
+            Severity::Critical => "CRITICAL",
+        };
+        write!(f, "{}", level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_severity_ordering() {
+        assert!(Severity::Trace < Severity::Debug);
+        assert!(Severity::Error < Severity::Critical);
+        assert!(Some(Severity::Warn) < Some(Severity::Error));
+        assert!(None < Some(Severity::Trace));
+    }
+
+    #[test]
+    fn test_severity_display() {
+        assert_eq!(Severity::Warn.to_string(), "WARN");
+        assert_eq!(Severity::Critical.to_string(), "CRITICAL");
+    }
+}
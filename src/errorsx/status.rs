@@ -0,0 +1,136 @@
+//! Standard HTTP reason phrases for [`ErrorX`] status codes
+//!
+//! [`ErrorX::from_status`] builds an error whose message and status are the canonical reason
+//! phrase for a status code, as registered with IANA.
+//!
+//! # Example
+//! ```
+//! # use crate::toolkit::errorsx::ErrorX;
+//!
+//! let err = ErrorX::from_status(404);
+//! assert_eq!(err.message(), "Not Found");
+//! assert_eq!(err.status_code(), &Some(404));
+//! ```
+
+use super::ErrorX;
+
+/// Message and status used for status codes without a standard reason phrase
+const UNKNOWN_STATUS: &str = "Unknown Status";
+
+/// Returns the standard reason phrase for an HTTP status code
+fn reason_phrase(code: u32) -> Option<&'static str> {
+    let phrase = match code {
+        100 => "Continue",
+        101 => "Switching Protocols",
+        102 => "Processing",
+        103 => "Early Hints",
+        200 => "OK",
+        201 => "Created",
+        202 => "Accepted",
+        203 => "Non-Authoritative Information",
+        204 => "No Content",
+        205 => "Reset Content",
+        206 => "Partial Content",
+        207 => "Multi-Status",
+        208 => "Already Reported",
+        226 => "IM Used",
+        300 => "Multiple Choices",
+        301 => "Moved Permanently",
+        302 => "Found",
+        303 => "See Other",
+        304 => "Not Modified",
+        305 => "Use Proxy",
+        307 => "Temporary Redirect",
+        308 => "Permanent Redirect",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        402 => "Payment Required",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        406 => "Not Acceptable",
+        407 => "Proxy Authentication Required",
+        408 => "Request Timeout",
+        409 => "Conflict",
+        410 => "Gone",
+        411 => "Length Required",
+        412 => "Precondition Failed",
+        413 => "Content Too Large",
+        414 => "URI Too Long",
+        415 => "Unsupported Media Type",
+        416 => "Range Not Satisfiable",
+        417 => "Expectation Failed",
+        418 => "I'm a teapot",
+        421 => "Misdirected Request",
+        422 => "Unprocessable Content",
+        423 => "Locked",
+        424 => "Failed Dependency",
+        425 => "Too Early",
+        426 => "Upgrade Required",
+        428 => "Precondition Required",
+        429 => "Too Many Requests",
+        431 => "Request Header Fields Too Large",
+        451 => "Unavailable For Legal Reasons",
+        500 => "Internal Server Error",
+        501 => "Not Implemented",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        505 => "HTTP Version Not Supported",
+        506 => "Variant Also Negotiates",
+        507 => "Insufficient Storage",
+        508 => "Loop Detected",
+        510 => "Not Extended",
+        511 => "Network Authentication Required",
+        _ => return None,
+    };
+    Some(phrase)
+}
+
+impl ErrorX {
+    /// Creates an ErrorX from an HTTP status code with its standard reason phrase
+    ///
+    /// The status code is set, and both the message and the status are set to the reason
+    /// phrase, e.g. `"Not Found"` for 404. Codes without a standard phrase use
+    /// `"Unknown Status"`.
+    ///
+    /// # Parameters
+    /// * `code` - The HTTP status code
+    ///
+    /// # Returns
+    /// * `Self` - A new ErrorX instance
+    #[track_caller]
+    pub fn from_status(code: u32) -> Self {
+        let phrase = reason_phrase(code).unwrap_or(UNKNOWN_STATUS);
+        ErrorX::builder(phrase)
+            .with_status_code(code)
+            .with_status(phrase)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_status() {
+        let err = ErrorX::from_status(404);
+        assert_eq!(err.message(), "Not Found");
+        assert_eq!(err.status(), &Some("Not Found".to_string()));
+        assert_eq!(err.status_code(), &Some(404));
+        assert_eq!(err.location().file(), "src/errorsx/status.rs");
+
+        let err = ErrorX::from_status(500);
+        assert_eq!(err.message(), "Internal Server Error");
+        assert!(err.is_server_error());
+    }
+
+    #[test]
+    fn test_from_status_unknown() {
+        let err = ErrorX::from_status(599);
+        assert_eq!(err.message(), "Unknown Status");
+        assert_eq!(err.status(), &Some("Unknown Status".to_string()));
+        assert_eq!(err.status_code(), &Some(599));
+    }
+}
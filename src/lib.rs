@@ -1,3 +1,17 @@
+// The string utilities only need `alloc`; everything else requires the `std` feature.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+// `ErrorX` carries a backtrace and rich context by design, so results returning it are large.
+#![allow(clippy::result_large_err)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
 pub mod errorsx;
+#[cfg(feature = "std")]
+mod macros;
+pub mod prelude;
 pub mod stringsx;
+#[cfg(feature = "testing")]
+mod testing;
+#[cfg(feature = "std")]
 pub mod uuidx;
@@ -0,0 +1,144 @@
+//! Convenience macros for constructing and returning [`ErrorX`](crate::errorsx::ErrorX) values
+//!
+//! The macros are exported at the crate root:
+//! - `errorx!`: Builds an `ErrorX` from `format!`-style arguments
+//! - `bail!`: Returns early with an `Err` built by `errorx!`
+//! - `ensure!`: Returns early with an `Err` built by `errorx!` unless a condition holds
+//!
+//! All of them capture the location of the macro invocation as the error location.
+//!
+//! # Example
+//! ```
+//! use crate::toolkit::{bail, errorsx::ErrorX};
+//!
+//! fn find_user(id: u32) -> Result<String, ErrorX> {
+//!     if id == 0 {
+//!         bail!("invalid id {id}");
+//!     }
+//!     Ok(format!("user-{id}"))
+//! }
+//!
+//! assert_eq!(find_user(0).unwrap_err().message(), "invalid id 0");
+//! ```
+
+/// Builds an [`ErrorX`](crate::errorsx::ErrorX) with a formatted message
+///
+/// Accepts the same arguments as `format!`. The error location is the macro call site.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::errorx;
+///
+/// let id = 42;
+/// let err = errorx!("user {} not found", id);
+/// assert_eq!(err.message(), "user 42 not found");
+/// ```
+#[macro_export]
+macro_rules! errorx {
+    ($($arg:tt)+) => {
+        $crate::errorsx::ErrorX::new(::std::format!($($arg)+))
+    };
+}
+
+/// Returns early with an `Err` containing an [`ErrorX`](crate::errorsx::ErrorX)
+///
+/// Equivalent to `return Err(errorx!(...))`, converting the error with `Into` so it also works
+/// in functions whose error type is convertible from `ErrorX`.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::{bail, errorsx::ErrorX};
+///
+/// fn check(value: i32) -> Result<i32, ErrorX> {
+///     if value < 0 {
+///         bail!("negative value {value}");
+///     }
+///     Ok(value)
+/// }
+///
+/// assert!(check(-1).is_err());
+/// ```
+#[macro_export]
+macro_rules! bail {
+    ($($arg:tt)+) => {
+        return ::std::result::Result::Err(::std::convert::Into::into($crate::errorx!($($arg)+)))
+    };
+}
+
+/// Returns early with an `Err` containing an [`ErrorX`](crate::errorsx::ErrorX) unless a
+/// condition holds
+///
+/// Equivalent to `if !cond { bail!(...) }`. The message arguments are only formatted when the
+/// condition is false.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::{ensure, errorsx::ErrorX};
+///
+/// fn check(value: i32) -> Result<i32, ErrorX> {
+///     ensure!(value >= 0, "negative value {}", value);
+///     Ok(value)
+/// }
+///
+/// assert_eq!(check(1).unwrap(), 1);
+/// assert_eq!(check(-1).unwrap_err().message(), "negative value -1");
+/// ```
+#[macro_export]
+macro_rules! ensure {
+    ($cond:expr, $($arg:tt)+) => {
+        if !$cond {
+            $crate::bail!($($arg)+);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errorsx::ErrorX;
+
+    fn fails(id: u32) -> Result<u32, ErrorX> {
+        if id == 0 {
+            bail!("invalid id {id}");
+        }
+        Ok(id)
+    }
+
+    /// The line of the `ensure!` call in `checked`
+    const ENSURE_LINE: u32 = line!() + 3;
+
+    fn checked(id: u32) -> Result<u32, ErrorX> {
+        ensure!(id != 0, "invalid id {}", id);
+        Ok(id)
+    }
+
+    #[test]
+    fn test_errorx() {
+        let name = "config.toml";
+        let err = errorx!("failed to read {}", name);
+        assert_eq!(err.message(), "failed to read config.toml");
+        assert_eq!(err.location().file(), "src/macros.rs");
+        assert_eq!(err.location().line(), line!() - 3);
+    }
+
+    #[test]
+    fn test_bail() {
+        assert_eq!(fails(7).unwrap(), 7);
+
+        let err = fails(0).unwrap_err();
+        assert_eq!(err.message(), "invalid id 0");
+        assert_eq!(err.location().file(), "src/macros.rs");
+    }
+
+    #[test]
+    fn test_ensure() {
+        assert_eq!(checked(7).unwrap(), 7);
+
+        let err = checked(0).unwrap_err();
+        assert_eq!(err.message(), "invalid id 0");
+        assert_eq!(err.location().file(), "src/macros.rs");
+        assert_eq!(err.location().line(), ENSURE_LINE);
+    }
+}
@@ -0,0 +1,120 @@
+//! Re-exports of the most commonly used toolkit items
+//!
+//! Import everything at once with:
+//!
+//! ```
+//! use toolkit::prelude::*;
+//! ```
+//!
+//! The prelude includes:
+//! - Errors: [`ErrorX`], [`ErrorXBuilder`], [`Severity`] and the `errorx!`/`bail!`/`ensure!`
+//!   macros
+//! - Error extension traits: [`Context`](crate::errorsx::Context) and
+//!   [`OptionExt`](crate::errorsx::OptionExt), imported anonymously so their methods are
+//!   available without clashing with other traits of the same names
+//! - UUID generators: [`new_v3`], [`new_v4`], [`new_v4_batch`], [`new_v4_iter`], [`new_v5`],
+//!   [`new_v7`], [`new_v7_at`]
+//! - Case conversion: [`to_lower_initials`], [`to_upper_initials`], [`to_snake_case`],
+//!   [`to_screaming_snake_case`], [`to_kebab_case`], [`to_camel_case`], [`to_pascal_case`],
+//!   [`to_title_case`]
+//! - Splitting: [`splitx`], [`splitx_n`], [`splitx_nonempty`], [`splitx_trim`],
+//!   [`splitx_trim_nonempty`], [`splitx_keep_sep`], [`splitx_any`], [`splitx_any_collapsed`]
+//! - Truncation: [`truncate_byte_len`], [`truncate_char_len`], [`truncate_with_ellipsis`]
+//!
+//! Items with generic names, such as `uuidx::parse` or `uuidx::max`, are left out to avoid
+//! clashing with names in the importing scope. Error and UUID items require the `std` feature.
+
+#[cfg(feature = "std")]
+pub use crate::errorsx::{Context as _, OptionExt as _};
+#[cfg(feature = "std")]
+pub use crate::errorsx::{ErrorX, ErrorXBuilder, Severity};
+pub use crate::stringsx::case::{
+    to_camel_case, to_kebab_case, to_lower_initials, to_pascal_case, to_screaming_snake_case,
+    to_snake_case, to_title_case, to_upper_initials,
+};
+pub use crate::stringsx::split::{
+    splitx, splitx_any, splitx_any_collapsed, splitx_keep_sep, splitx_n, splitx_nonempty,
+    splitx_trim, splitx_trim_nonempty,
+};
+pub use crate::stringsx::truncate::{truncate_byte_len, truncate_char_len, truncate_with_ellipsis};
+#[cfg(feature = "std")]
+pub use crate::uuidx::{new_v3, new_v4, new_v4_batch, new_v4_iter, new_v5, new_v7, new_v7_at};
+#[cfg(feature = "std")]
+pub use crate::{bail, ensure, errorx};
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[cfg(feature = "std")]
+    fn fails() -> Result<(), ErrorX> {
+        bail!("failed with {}", 42);
+    }
+
+    #[cfg(feature = "std")]
+    fn ensures(value: i32) -> Result<(), ErrorX> {
+        ensure!(value > 0, "not positive: {}", value);
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_prelude_errors() {
+        let err: ErrorX = ErrorXBuilder::init("Built")
+            .with_severity(Severity::Warn)
+            .build();
+        assert_eq!(err.message(), "Built");
+        assert_eq!(errorx!("code {}", 7).message(), "code 7");
+        assert_eq!(fails().unwrap_err().message(), "failed with 42");
+        assert_eq!(ensures(-1).unwrap_err().message(), "not positive: -1");
+
+        let result: Result<(), std::fmt::Error> = Err(std::fmt::Error);
+        assert!(result.with_errorx_context("formatting").is_err());
+        assert!(None::<u32>.ok_or_errorx("missing").is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_prelude_uuids() {
+        use std::time::SystemTime;
+
+        assert_eq!(
+            new_v3(crate::uuidx::NAMESPACE_DNS, b"a").get_version_num(),
+            3
+        );
+        assert_eq!(new_v4().get_version_num(), 4);
+        assert_eq!(new_v4_batch(2).len(), 2);
+        assert_eq!(new_v4_iter().take(2).count(), 2);
+        assert_eq!(
+            new_v5(crate::uuidx::NAMESPACE_DNS, b"a").get_version_num(),
+            5
+        );
+        assert_eq!(new_v7().get_version_num(), 7);
+        assert_eq!(new_v7_at(SystemTime::now()).get_version_num(), 7);
+    }
+
+    #[test]
+    fn test_prelude_strings() {
+        assert_eq!(to_lower_initials("Hello"), "hello");
+        assert_eq!(to_upper_initials("hello"), "Hello");
+        assert_eq!(to_snake_case("helloWorld"), "hello_world");
+        assert_eq!(to_screaming_snake_case("helloWorld"), "HELLO_WORLD");
+        assert_eq!(to_kebab_case("helloWorld"), "hello-world");
+        assert_eq!(to_camel_case("hello_world"), "helloWorld");
+        assert_eq!(to_pascal_case("hello_world"), "HelloWorld");
+        assert_eq!(to_title_case("the end"), "The End");
+
+        assert_eq!(splitx("a,b", ","), vec!["a", "b"]);
+        assert_eq!(splitx_n("a,b,c", ",", 2), vec!["a", "b,c"]);
+        assert_eq!(splitx_nonempty(",a,,b", ","), vec!["a", "b"]);
+        assert_eq!(splitx_trim(" a , ", ","), vec!["a", ""]);
+        assert_eq!(splitx_trim_nonempty(" a , ", ","), vec!["a"]);
+        assert_eq!(splitx_keep_sep("a,b", ","), vec!["a", ",", "b"]);
+        assert_eq!(splitx_any("a;b", &[';']), vec!["a", "b"]);
+        assert_eq!(splitx_any_collapsed(";a;;b", &[';']), vec!["a", "b"]);
+
+        assert_eq!(truncate_byte_len("Hello", 2), "He");
+        assert_eq!(truncate_char_len("Hello", 2), "He");
+        assert_eq!(truncate_with_ellipsis("Hello", 3, "…"), "He…");
+    }
+}
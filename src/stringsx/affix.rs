@@ -0,0 +1,89 @@
+//! Provides case-insensitive prefix and suffix stripping.
+//!
+//! Comparisons ignore ASCII case only, non-ASCII characters must match exactly.
+//!
+//! # Example
+//! ```
+//! use crate::toolkit::stringsx::affix::strip_prefix_ci;
+//!
+//! assert_eq!(strip_prefix_ci("bearer abc123", "Bearer "), Some("abc123"));
+//! ```
+
+/// Removes a prefix from a string, ignoring ASCII case.
+///
+/// # Arguments
+///
+/// * `s` - The input string
+/// * `prefix` - The prefix to remove
+///
+/// # Returns
+///
+/// The remainder of `s` after the prefix, or `None` if `s` does not start with it.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::affix::strip_prefix_ci;
+///
+/// assert_eq!(strip_prefix_ci("BEARER token", "Bearer "), Some("token"));
+/// assert_eq!(strip_prefix_ci("Basic token", "Bearer "), None);
+/// ```
+pub fn strip_prefix_ci<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = s.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &s[prefix.len()..])
+}
+
+/// Removes a suffix from a string, ignoring ASCII case.
+///
+/// # Arguments
+///
+/// * `s` - The input string
+/// * `suffix` - The suffix to remove
+///
+/// # Returns
+///
+/// The part of `s` before the suffix, or `None` if `s` does not end with it.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::affix::strip_suffix_ci;
+///
+/// assert_eq!(strip_suffix_ci("report.PDF", ".pdf"), Some("report"));
+/// ```
+pub fn strip_suffix_ci<'a>(s: &'a str, suffix: &str) -> Option<&'a str> {
+    let split = s.len().checked_sub(suffix.len())?;
+    let tail = s.get(split..)?;
+    tail.eq_ignore_ascii_case(suffix).then(|| &s[..split])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_prefix_ci() {
+        assert_eq!(strip_prefix_ci("Bearer abc", "Bearer "), Some("abc"));
+        assert_eq!(strip_prefix_ci("bEaReR abc", "Bearer "), Some("abc"));
+        assert_eq!(strip_prefix_ci("Bearer", "Bearer"), Some(""));
+        assert_eq!(strip_prefix_ci("anything", ""), Some("anything"));
+    }
+
+    #[test]
+    fn test_strip_prefix_ci_no_match() {
+        assert_eq!(strip_prefix_ci("Basic abc", "Bearer "), None);
+        assert_eq!(strip_prefix_ci("Bear", "Bearer "), None);
+        assert_eq!(strip_prefix_ci("🚧abc", "xa"), None);
+        assert_eq!(strip_prefix_ci("Ébc", "éb"), None);
+    }
+
+    #[test]
+    fn test_strip_suffix_ci() {
+        assert_eq!(strip_suffix_ci("report.PDF", ".pdf"), Some("report"));
+        assert_eq!(strip_suffix_ci("Report.Pdf", ".PDF"), Some("Report"));
+        assert_eq!(strip_suffix_ci("report.pdf", ".txt"), None);
+        assert_eq!(strip_suffix_ci("abc🚧", "xa"), None);
+        assert_eq!(strip_suffix_ci("pdf", ".pdf"), None);
+    }
+}
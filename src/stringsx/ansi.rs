@@ -0,0 +1,143 @@
+//! Provides removal of ANSI escape sequences.
+//!
+//! Colored terminal output contains escape sequences that are noise in log files. The
+//! following sequences are recognized, each introduced by the `ESC` (`\x1b`) character:
+//! - CSI sequences such as SGR colors (`\x1b[31m`) and cursor movement (`\x1b[2K`)
+//! - OSC sequences such as hyperlinks and window titles, ended by `BEL` or `ESC \`
+//! - Two-character escapes such as `\x1b7`
+//!
+//! # Example
+//! ```
+//! use crate::toolkit::stringsx::ansi::{has_ansi, strip_ansi};
+//!
+//! let colored = "\x1b[1;32mok\x1b[0m";
+//! assert!(has_ansi(colored));
+//! assert_eq!(strip_ansi(colored), "ok");
+//! ```
+
+use alloc::string::String;
+
+/// The escape character that introduces every ANSI sequence
+const ESC: char = '\x1b';
+
+/// The bell character that can end an OSC sequence
+const BEL: char = '\x07';
+
+/// Removes ANSI escape sequences from a string.
+///
+/// An unterminated sequence at the end of the input is removed as well.
+///
+/// # Arguments
+///
+/// * `s` - The input string, possibly containing escape sequences
+///
+/// # Returns
+///
+/// A new string containing only the text outside of escape sequences.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::ansi::strip_ansi;
+///
+/// assert_eq!(strip_ansi("\x1b[31merror\x1b[0m: failed"), "error: failed");
+/// assert_eq!(strip_ansi("plain text"), "plain text");
+/// ```
+pub fn strip_ansi(s: &str) -> String {
+    let mut stripped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != ESC {
+            stripped.push(c);
+            continue;
+        }
+        match chars.next() {
+            // CSI: parameter and intermediate bytes up to a final byte in `@`..=`~`
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC: any text up to `BEL` or the `ESC \` string terminator
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == BEL || (c == ESC && chars.next().is_some()) {
+                        break;
+                    }
+                }
+            }
+            // Any other escape consumes exactly one character
+            _ => {}
+        }
+    }
+    stripped
+}
+
+/// Checks whether a string contains ANSI escape sequences.
+///
+/// # Arguments
+///
+/// * `s` - The input string to check
+///
+/// # Returns
+///
+/// `true` if `s` contains the `ESC` character that introduces an escape sequence.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::ansi::has_ansi;
+///
+/// assert!(has_ansi("\x1b[1mbold\x1b[0m"));
+/// assert!(!has_ansi("plain text"));
+/// ```
+pub fn has_ansi(s: &str) -> bool {
+    s.contains(ESC)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_ansi_colored() {
+        let colored = "\x1b[1;31mERROR\x1b[0m \x1b[38;5;208mdisk full\x1b[m";
+        assert_eq!(strip_ansi(colored), "ERROR disk full");
+        assert!(!has_ansi(&strip_ansi(colored)));
+    }
+
+    #[test]
+    fn test_strip_ansi_plain() {
+        assert_eq!(
+            strip_ansi("plain [text] ~ ünïcode"),
+            "plain [text] ~ ünïcode"
+        );
+        assert_eq!(strip_ansi(""), "");
+    }
+
+    #[test]
+    fn test_strip_ansi_cursor_and_osc() {
+        assert_eq!(strip_ansi("\x1b[2K\x1b[1Gprogress"), "progress");
+        assert_eq!(
+            strip_ansi("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\"),
+            "link"
+        );
+        assert_eq!(strip_ansi("\x1b]0;title\x07text"), "text");
+        assert_eq!(strip_ansi("\x1b7saved\x1b8"), "saved");
+    }
+
+    #[test]
+    fn test_strip_ansi_unterminated() {
+        assert_eq!(strip_ansi("text\x1b[31"), "text");
+        assert_eq!(strip_ansi("text\x1b"), "text");
+    }
+
+    #[test]
+    fn test_has_ansi() {
+        assert!(has_ansi("\x1b[32mok\x1b[0m"));
+        assert!(!has_ansi("ok"));
+    }
+}
@@ -0,0 +1,102 @@
+//! Provides string building utilities for repeated and joined parts.
+//!
+//! # Example
+//! ```
+//! use crate::toolkit::stringsx::build::repeat_join;
+//!
+//! let sql = format!("INSERT INTO t VALUES ({})", repeat_join("?", ", ", 3));
+//! assert_eq!(sql, "INSERT INTO t VALUES (?, ?, ?)");
+//! ```
+
+use alloc::{string::String, vec::Vec};
+
+/// Repeats a part `n` times with a separator between each repetition.
+///
+/// The output is allocated once with its exact final capacity.
+///
+/// # Arguments
+///
+/// * `part` - The string to repeat
+/// * `sep` - The separator placed between repetitions
+/// * `n` - The number of repetitions
+///
+/// # Returns
+///
+/// A new string holding `n` copies of `part` separated by `sep`, or an empty string if `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::build::repeat_join;
+///
+/// assert_eq!(repeat_join("?", ", ", 3), "?, ?, ?");
+/// assert_eq!(repeat_join("-", "", 4), "----");
+/// ```
+pub fn repeat_join(part: &str, sep: &str, n: usize) -> String {
+    if n == 0 {
+        return String::new();
+    }
+    let mut joined = String::with_capacity(part.len() * n + sep.len() * (n - 1));
+    joined.push_str(part);
+    for _ in 1..n {
+        joined.push_str(sep);
+        joined.push_str(part);
+    }
+    joined
+}
+
+/// Joins the non-empty parts of a slice with a separator.
+///
+/// Empty parts are skipped entirely, so they never produce doubled separators.
+///
+/// # Arguments
+///
+/// * `parts` - The strings to join
+/// * `sep` - The separator placed between parts
+///
+/// # Returns
+///
+/// A new string holding the non-empty parts separated by `sep`.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::build::join_non_empty;
+///
+/// assert_eq!(join_non_empty(&["Main St", "", "Springfield"], ", "), "Main St, Springfield");
+/// ```
+pub fn join_non_empty(parts: &[&str], sep: &str) -> String {
+    parts
+        .iter()
+        .copied()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(sep)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeat_join() {
+        assert_eq!(repeat_join("?", ", ", 3), "?, ?, ?");
+        assert_eq!(repeat_join("?", ", ", 1), "?");
+        assert_eq!(repeat_join("?", ", ", 0), "");
+        assert_eq!(repeat_join("🚧", "|", 2), "🚧|🚧");
+    }
+
+    #[test]
+    fn test_repeat_join_exact_capacity() {
+        let joined = repeat_join("($1)", ", ", 5);
+        assert_eq!(joined.len(), joined.capacity());
+    }
+
+    #[test]
+    fn test_join_non_empty() {
+        assert_eq!(join_non_empty(&["a", "", "b", ""], ","), "a,b");
+        assert_eq!(join_non_empty(&["", "", ""], ","), "");
+        assert_eq!(join_non_empty(&[], ","), "");
+        assert_eq!(join_non_empty(&[" ", "x"], "-"), " -x");
+    }
+}
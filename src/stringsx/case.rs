@@ -3,6 +3,22 @@
 //! It contains functions for manipulating the case of strings, specifically:
 //! - Converting first character to lowercase
 //! - Converting first character to uppercase
+//! - Converting the first character in place, without allocating for ASCII initials
+//! - Checking whether first-character conversions would change a string
+//! - Converting identifiers to snake_case, SCREAMING_SNAKE_CASE, kebab-case, camelCase and
+//!   PascalCase
+//! - Converting phrases to Title Case
+//!
+//! # Round-tripping
+//! Converting a well-formed snake_case identifier to camelCase and back returns the original
+//! identifier. Well-formed means lowercase words joined by single underscores, where every word
+//! after the first starts with a letter. The conversions are lossy elsewhere:
+//! - Acronyms are normalized, so `HTTPServer` becomes `http_server` and then `httpServer`
+//! - A word starting with a digit merges into the previous word, so `version_2_update` becomes
+//!   `version2Update` and then `version2_update`
+//! - Consecutive single-letter words after the first read back as an acronym, so `a_b_c`
+//!   becomes `aBC` and then `a_bc`
+//! - Leading, trailing and repeated separators are dropped
 //!
 //! # Performance Notes
 //! The functions in this module are optimized for performance but may be further
@@ -10,12 +26,19 @@
 //!
 //! # Examples
 //! ```
-//! use crate::toolkit::stringsx::case::{to_lower_initials, to_upper_initials};
+//! use crate::toolkit::stringsx::case::{to_lower_initials, to_snake_case, to_upper_initials};
 //!
 //! assert_eq!(to_lower_initials("Hello"), "hello");
 //! assert_eq!(to_upper_initials("world"), "World");
+//! assert_eq!(to_snake_case("HelloWorld"), "hello_world");
 //! ```
 
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
 /// Converts the first character of a string to lowercase and returns the modified string
 ///
 /// # Arguments
@@ -76,6 +99,432 @@ pub fn to_upper_initials(s: &str) -> String {
     format!("{}{}", initial, remaining)
 }
 
+/// Converts the first character of a string to lowercase in place
+///
+/// ASCII initials are changed without reallocating. A multibyte initial whose lowercase form
+/// has a different length is replaced within the string, which may reallocate.
+///
+/// # Arguments
+///
+/// * `s` - A mutable String that will have its first character converted to lowercase
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::case::lower_initial_in_place;
+///
+/// let mut s = String::from("Hello");
+/// lower_initial_in_place(&mut s);
+/// assert_eq!(s, "hello");
+/// ```
+pub fn lower_initial_in_place(s: &mut String) {
+    let Some(initial) = s.chars().next() else {
+        return;
+    };
+    if initial.is_ascii() {
+        s[..1].make_ascii_lowercase();
+    } else {
+        let lower = initial.to_lowercase().to_string();
+        s.replace_range(..initial.len_utf8(), &lower);
+    }
+}
+
+/// Converts the first character of a string to uppercase in place
+///
+/// ASCII initials are changed without reallocating. A multibyte initial whose uppercase form
+/// has a different length is replaced within the string, which may reallocate.
+///
+/// # Arguments
+///
+/// * `s` - A mutable String that will have its first character converted to uppercase
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::case::upper_initial_in_place;
+///
+/// let mut s = String::from("hello");
+/// upper_initial_in_place(&mut s);
+/// assert_eq!(s, "Hello");
+/// ```
+pub fn upper_initial_in_place(s: &mut String) {
+    let Some(initial) = s.chars().next() else {
+        return;
+    };
+    if initial.is_ascii() {
+        s[..1].make_ascii_uppercase();
+    } else {
+        let upper = initial.to_uppercase().to_string();
+        s.replace_range(..initial.len_utf8(), &upper);
+    }
+}
+
+/// Checks whether [`to_lower_initials`] would change the string
+///
+/// Lets callers skip the allocation when the first character is already lowercase.
+///
+/// # Arguments
+///
+/// * `s` - A string slice to check
+///
+/// # Returns
+///
+/// * `true` if lowercasing the first character changes it, `false` for empty strings or when
+///   the first character is already lowercase or uncased
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::case::would_change_lower_initial;
+///
+/// assert!(would_change_lower_initial("Hello"));
+/// assert!(!would_change_lower_initial("hello"));
+/// ```
+pub fn would_change_lower_initial(s: &str) -> bool {
+    s.chars()
+        .next()
+        .is_some_and(|initial| initial.to_lowercase().ne(core::iter::once(initial)))
+}
+
+/// Checks whether [`to_upper_initials`] would change the string
+///
+/// Lets callers skip the allocation when the first character is already uppercase.
+///
+/// # Arguments
+///
+/// * `s` - A string slice to check
+///
+/// # Returns
+///
+/// * `true` if uppercasing the first character changes it, `false` for empty strings or when
+///   the first character is already uppercase or uncased
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::case::would_change_upper_initial;
+///
+/// assert!(would_change_upper_initial("hello"));
+/// assert!(!would_change_upper_initial("Hello"));
+/// ```
+pub fn would_change_upper_initial(s: &str) -> bool {
+    s.chars()
+        .next()
+        .is_some_and(|initial| initial.to_uppercase().ne(core::iter::once(initial)))
+}
+
+/// Small words that stay lowercase in title case unless they start the phrase
+const TITLE_CASE_SMALL_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "in", "nor", "of", "on", "or", "the", "to",
+];
+
+/// Splits an identifier or phrase into its words
+///
+/// Any non-alphanumeric character acts as a separator and runs of separators never produce
+/// empty words. Within a run of alphanumerics a new word starts at an uppercase character that
+/// follows a lowercase character or a digit (`helloWorld`), or at the last uppercase character
+/// of an acronym that is followed by a lowercase character (`HTTPServer`). Digits stay attached
+/// to the word they follow.
+fn split_words(s: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = None;
+    let mut prev: Option<char> = None;
+    let mut chars = s.char_indices().peekable();
+
+    while let Some((idx, c)) = chars.next() {
+        if !c.is_alphanumeric() {
+            if let Some(word_start) = start.take() {
+                words.push(&s[word_start..idx]);
+            }
+            prev = None;
+            continue;
+        }
+
+        if let (Some(word_start), Some(p)) = (start, prev) {
+            let next_is_lower = chars.peek().is_some_and(|&(_, n)| n.is_lowercase());
+            let is_boundary = c.is_uppercase()
+                && (p.is_lowercase() || p.is_numeric() || (p.is_uppercase() && next_is_lower));
+            if is_boundary {
+                words.push(&s[word_start..idx]);
+                start = Some(idx);
+            }
+        } else {
+            start = Some(idx);
+        }
+        prev = Some(c);
+    }
+
+    if let Some(word_start) = start {
+        words.push(&s[word_start..]);
+    }
+    words
+}
+
+/// Converts a string to snake_case
+///
+/// Words are detected at case boundaries and separators (spaces, hyphens, underscores and other
+/// punctuation), lowercased and joined with underscores.
+///
+/// # Arguments
+///
+/// * `s` - A string slice in any casing style
+///
+/// # Returns
+///
+/// * A new String in snake_case
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::case::to_snake_case;
+///
+/// assert_eq!(to_snake_case("helloWorld"), "hello_world");
+/// assert_eq!(to_snake_case("HTTPServer"), "http_server");
+/// assert_eq!(to_snake_case("--hello world--"), "hello_world");
+/// ```
+pub fn to_snake_case(s: &str) -> String {
+    split_words(s)
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Converts a string to SCREAMING_SNAKE_CASE
+///
+/// Words are detected the same way as in [`to_snake_case`], uppercased and joined with
+/// underscores.
+///
+/// # Arguments
+///
+/// * `s` - A string slice in any casing style
+///
+/// # Returns
+///
+/// * A new String in SCREAMING_SNAKE_CASE
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::case::to_screaming_snake_case;
+///
+/// assert_eq!(to_screaming_snake_case("parseHTTPResponse"), "PARSE_HTTP_RESPONSE");
+/// ```
+pub fn to_screaming_snake_case(s: &str) -> String {
+    split_words(s)
+        .iter()
+        .map(|word| word.to_uppercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Converts a string to kebab-case
+///
+/// Words are detected the same way as in [`to_snake_case`], lowercased and joined with hyphens.
+/// Runs of separators and punctuation collapse into a single hyphen and the result never starts
+/// or ends with a hyphen.
+///
+/// # Arguments
+///
+/// * `s` - A string slice in any casing style
+///
+/// # Returns
+///
+/// * A new String in kebab-case
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::case::to_kebab_case;
+///
+/// assert_eq!(to_kebab_case("HelloWorld"), "hello-world");
+/// assert_eq!(to_kebab_case("  Hello   World  "), "hello-world");
+/// ```
+pub fn to_kebab_case(s: &str) -> String {
+    split_words(s)
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Converts a string to camelCase
+///
+/// Words are detected the same way as in [`to_snake_case`]. The first word is lowercased and
+/// every following word is capitalized, so acronyms are normalized (`HTTPServer` becomes
+/// `httpServer`).
+///
+/// # Arguments
+///
+/// * `s` - A string slice in any casing style
+///
+/// # Returns
+///
+/// * A new String in camelCase
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::case::to_camel_case;
+///
+/// assert_eq!(to_camel_case("hello_world"), "helloWorld");
+/// assert_eq!(to_camel_case("Hello World"), "helloWorld");
+/// ```
+pub fn to_camel_case(s: &str) -> String {
+    split_words(s)
+        .iter()
+        .enumerate()
+        .map(|(idx, word)| {
+            if idx == 0 {
+                word.to_lowercase()
+            } else {
+                to_upper_initials(&word.to_lowercase())
+            }
+        })
+        .collect()
+}
+
+/// Converts a string to PascalCase
+///
+/// Words are detected the same way as in [`to_snake_case`] and every word is capitalized.
+///
+/// # Arguments
+///
+/// * `s` - A string slice in any casing style
+///
+/// # Returns
+///
+/// * A new String in PascalCase
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::case::to_pascal_case;
+///
+/// assert_eq!(to_pascal_case("hello_world"), "HelloWorld");
+/// assert_eq!(to_pascal_case("hello-world"), "HelloWorld");
+/// ```
+pub fn to_pascal_case(s: &str) -> String {
+    split_words(s)
+        .iter()
+        .map(|word| to_upper_initials(&word.to_lowercase()))
+        .collect()
+}
+
+/// Converts a phrase to Title Case
+///
+/// Uppercases the first letter of each whitespace-delimited word and lowercases the rest.
+/// Common small words ("a", "the", "of", ...) stay lowercase unless they start the phrase.
+/// Whitespace is preserved as is.
+///
+/// # Arguments
+///
+/// * `s` - A string slice containing the phrase
+///
+/// # Returns
+///
+/// * A new String in Title Case
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::case::to_title_case;
+///
+/// assert_eq!(to_title_case("the lord of the rings"), "The Lord of the Rings");
+/// ```
+pub fn to_title_case(s: &str) -> String {
+    to_title_case_with_exceptions(s, TITLE_CASE_SMALL_WORDS)
+}
+
+/// Converts a phrase to Title Case with a custom list of words kept lowercase
+///
+/// Behaves like [`to_title_case`], but the words that stay lowercase (unless they start the
+/// phrase) are taken from `exceptions`, compared case-insensitively.
+///
+/// # Arguments
+///
+/// * `s` - A string slice containing the phrase
+/// * `exceptions` - Words that stay lowercase when not at the start
+///
+/// # Returns
+///
+/// * A new String in Title Case
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::case::to_title_case_with_exceptions;
+///
+/// let result = to_title_case_with_exceptions("war and peace", &[]);
+/// assert_eq!(result, "War And Peace");
+/// ```
+pub fn to_title_case_with_exceptions(s: &str, exceptions: &[&str]) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut word_start = None;
+    let mut is_first = true;
+    let mut push_word = |result: &mut String, word: &str| {
+        let lower = word.to_lowercase();
+        let is_exception = exceptions.iter().any(|e| e.to_lowercase() == lower);
+        if is_first || !is_exception {
+            result.push_str(&to_upper_initials(&lower));
+        } else {
+            result.push_str(&lower);
+        }
+        is_first = false;
+    };
+
+    for (idx, c) in s.char_indices() {
+        if c.is_whitespace() {
+            if let Some(start) = word_start.take() {
+                push_word(&mut result, &s[start..idx]);
+            }
+            result.push(c);
+        } else if word_start.is_none() {
+            word_start = Some(idx);
+        }
+    }
+    if let Some(start) = word_start {
+        push_word(&mut result, &s[start..]);
+    }
+    result
+}
+
+/// Swaps the case of every cased character in a string
+///
+/// Uppercase characters become lowercase and vice versa, while characters without case, such
+/// as digits, punctuation and most non-Latin scripts, are left unchanged. Full Unicode case
+/// mappings are used, so a single character may expand, e.g. `ß` becomes `SS`.
+///
+/// # Arguments
+///
+/// * `s` - A string slice to transform
+///
+/// # Returns
+///
+/// * A new String with the case of each character swapped
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::case::swap_case;
+///
+/// assert_eq!(swap_case("Hello World"), "hELLO wORLD");
+/// assert_eq!(swap_case("Éclair"), "éCLAIR");
+/// ```
+pub fn swap_case(s: &str) -> String {
+    let mut swapped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c.is_uppercase() {
+            swapped.extend(c.to_lowercase());
+        } else if c.is_lowercase() {
+            swapped.extend(c.to_uppercase());
+        } else {
+            swapped.push(c);
+        }
+    }
+    swapped
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,4 +544,235 @@ mod tests {
         assert_eq!(to_upper_initials("WORLD"), "WORLD");
         assert_eq!(to_upper_initials(""), "");
     }
+
+    #[test]
+    fn test_initial_in_place_ascii_does_not_allocate() {
+        let mut s = String::from("Hello World");
+        let (ptr, capacity) = (s.as_ptr(), s.capacity());
+        lower_initial_in_place(&mut s);
+        assert_eq!(s, "hello World");
+        assert_eq!((s.as_ptr(), s.capacity()), (ptr, capacity));
+
+        upper_initial_in_place(&mut s);
+        assert_eq!(s, "Hello World");
+        assert_eq!((s.as_ptr(), s.capacity()), (ptr, capacity));
+    }
+
+    #[test]
+    fn test_initial_in_place_multibyte() {
+        let mut s = String::from("Éclair");
+        lower_initial_in_place(&mut s);
+        assert_eq!(s, "éclair");
+        upper_initial_in_place(&mut s);
+        assert_eq!(s, "Éclair");
+
+        let mut s = String::from("ßtraße");
+        upper_initial_in_place(&mut s);
+        assert_eq!(s, to_upper_initials("ßtraße"));
+
+        let mut empty = String::new();
+        lower_initial_in_place(&mut empty);
+        upper_initial_in_place(&mut empty);
+        assert_eq!(empty, "");
+    }
+
+    #[test]
+    fn test_would_change_initials() {
+        for input in ["Hello", "hello", "", "1abc", "Éclair", "éclair", "ßtraße"] {
+            assert_eq!(
+                would_change_lower_initial(input),
+                to_lower_initials(input) != input
+            );
+            assert_eq!(
+                would_change_upper_initial(input),
+                to_upper_initials(input) != input
+            );
+        }
+        assert!(!would_change_lower_initial(""));
+        assert!(!would_change_upper_initial(""));
+        assert!(!would_change_lower_initial("hello"));
+        assert!(!would_change_upper_initial("Hello"));
+    }
+
+    #[test]
+    fn test_split_words() {
+        assert_eq!(split_words("helloWorld"), vec!["hello", "World"]);
+        assert_eq!(split_words("HTTPServer"), vec!["HTTP", "Server"]);
+        assert_eq!(split_words("version2Update"), vec!["version2", "Update"]);
+        assert_eq!(split_words(" __a--b "), vec!["a", "b"]);
+        assert_eq!(split_words(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_to_snake_case() {
+        assert_eq!(to_snake_case("HelloWorld"), "hello_world");
+        assert_eq!(to_snake_case("helloWorld"), "hello_world");
+        assert_eq!(to_snake_case("HTTPServer"), "http_server");
+        assert_eq!(to_snake_case("hello world"), "hello_world");
+        assert_eq!(to_snake_case("hello-world"), "hello_world");
+        assert_eq!(to_snake_case("hello__world"), "hello_world");
+        assert_eq!(to_snake_case("_hello world-"), "hello_world");
+        assert_eq!(to_snake_case("already_snake"), "already_snake");
+        assert_eq!(to_snake_case(""), "");
+    }
+
+    #[test]
+    fn test_to_screaming_snake_case() {
+        assert_eq!(to_screaming_snake_case("helloWorld"), "HELLO_WORLD");
+        assert_eq!(
+            to_screaming_snake_case("parseHTTPResponse"),
+            "PARSE_HTTP_RESPONSE"
+        );
+        assert_eq!(
+            to_screaming_snake_case("max-retry-count"),
+            "MAX_RETRY_COUNT"
+        );
+        assert_eq!(
+            to_screaming_snake_case("max retry count"),
+            "MAX_RETRY_COUNT"
+        );
+        assert_eq!(
+            to_screaming_snake_case("ALREADY_SCREAMING"),
+            "ALREADY_SCREAMING"
+        );
+        assert_eq!(to_screaming_snake_case(""), "");
+    }
+
+    #[test]
+    fn test_to_kebab_case() {
+        assert_eq!(to_kebab_case("HelloWorld"), "hello-world");
+        assert_eq!(to_kebab_case("hello_world"), "hello-world");
+        assert_eq!(to_kebab_case("  Hello   World  "), "hello-world");
+        assert_eq!(to_kebab_case("Hello, World!"), "hello-world");
+        assert_eq!(to_kebab_case("--hello--world--"), "hello-world");
+        assert_eq!(to_kebab_case("HTTPServer"), "http-server");
+        assert_eq!(to_kebab_case(""), "");
+    }
+
+    #[test]
+    fn test_to_camel_case() {
+        assert_eq!(to_camel_case("hello_world"), "helloWorld");
+        assert_eq!(to_camel_case("hello-world"), "helloWorld");
+        assert_eq!(to_camel_case("hello world"), "helloWorld");
+        assert_eq!(to_camel_case("hello_big-bad world"), "helloBigBadWorld");
+        assert_eq!(to_camel_case("helloWorld"), "helloWorld");
+        assert_eq!(to_camel_case("HTTPServer"), "httpServer");
+        assert_eq!(to_camel_case("version_2_update"), "version2Update");
+        assert_eq!(to_camel_case("user_id2"), "userId2");
+        assert_eq!(to_camel_case(""), "");
+    }
+
+    #[test]
+    fn test_to_pascal_case() {
+        assert_eq!(to_pascal_case("hello_world"), "HelloWorld");
+        assert_eq!(to_pascal_case("hello-world"), "HelloWorld");
+        assert_eq!(to_pascal_case("hello_big-bad world"), "HelloBigBadWorld");
+        assert_eq!(to_pascal_case("HelloWorld"), "HelloWorld");
+        assert_eq!(to_pascal_case("helloWorld"), "HelloWorld");
+        assert_eq!(to_pascal_case("parseHTTPResponse"), "ParseHttpResponse");
+        assert_eq!(to_pascal_case("user_id2"), "UserId2");
+        assert_eq!(to_pascal_case(""), "");
+    }
+
+    #[test]
+    fn test_to_title_case() {
+        assert_eq!(
+            to_title_case("the lord of the rings"),
+            "The Lord of the Rings"
+        );
+        assert_eq!(
+            to_title_case("THE LORD OF THE RINGS"),
+            "The Lord of the Rings"
+        );
+        assert_eq!(
+            to_title_case("a tale of two cities"),
+            "A Tale of Two Cities"
+        );
+        assert_eq!(to_title_case("  hello\tworld "), "  Hello\tWorld ");
+        assert_eq!(to_title_case(""), "");
+    }
+
+    #[test]
+    fn test_to_title_case_with_exceptions() {
+        assert_eq!(
+            to_title_case_with_exceptions("war and peace", &[]),
+            "War And Peace"
+        );
+        assert_eq!(
+            to_title_case_with_exceptions("the cat IN the hat", &["In", "the"]),
+            "The Cat in the Hat"
+        );
+    }
+
+    #[test]
+    fn test_swap_case() {
+        assert_eq!(swap_case("Hello World"), "hELLO wORLD");
+        assert_eq!(swap_case(""), "");
+        assert_eq!(swap_case("123 _-!"), "123 _-!");
+        assert_eq!(swap_case(&swap_case("Round Trip")), "Round Trip");
+    }
+
+    #[test]
+    fn test_swap_case_multibyte() {
+        assert_eq!(swap_case("ÀéÎõ"), "àÉîÕ");
+        assert_eq!(swap_case("Straße"), "sTRASSE");
+        assert_eq!(swap_case("Привет мир"), "пРИВЕТ МИР");
+        assert_eq!(swap_case("Abc日本語Xyz"), "aBC日本語xYZ");
+    }
+
+    /// Well-formed snake_case identifiers that survive a camelCase round trip
+    const SNAKE_ROUND_TRIP_CORPUS: &[&str] = &[
+        "x",
+        "hello",
+        "hello_world",
+        "user_id",
+        "user_id2",
+        "http_server",
+        "parse_http_response",
+        "max_retry_count",
+        "a_b",
+        "user_a_id",
+        "io_error",
+        "to_string",
+        "is_v4",
+        "v2_api",
+        "utf8_decoder",
+        "created_at_ms",
+    ];
+
+    #[test]
+    fn test_snake_camel_round_trip_corpus() {
+        for &snake in SNAKE_ROUND_TRIP_CORPUS {
+            let camel = to_camel_case(snake);
+            assert_eq!(to_snake_case(&camel), snake, "via {camel:?}");
+            assert_eq!(to_camel_case(&to_snake_case(&camel)), camel);
+        }
+    }
+
+    #[test]
+    fn test_snake_camel_round_trip_generated() {
+        let words = ["id", "user", "http", "v2", "x9y"];
+        for first in ["a"].into_iter().chain(words) {
+            for second in words {
+                for third in words {
+                    let snake = format!("{first}_{second}_{third}");
+                    assert_eq!(to_snake_case(&to_camel_case(&snake)), snake);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_snake_camel_round_trip_lossy() {
+        assert_eq!(to_camel_case(&to_snake_case("HTTPServer")), "httpServer");
+        assert_eq!(
+            to_snake_case(&to_camel_case("version_2_update")),
+            "version2_update"
+        );
+        assert_eq!(to_snake_case(&to_camel_case("a_b_c")), "a_bc");
+        assert_eq!(
+            to_snake_case(&to_camel_case("_hello__world_")),
+            "hello_world"
+        );
+    }
 }
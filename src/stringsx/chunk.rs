@@ -0,0 +1,107 @@
+//! Provides splitting of strings into fixed-size character chunks.
+//!
+//! Chunk sizes are measured in characters, so multi-byte characters are never split. This is
+//! useful for grouping digits, hex dumps and similar fixed-width formatting.
+//!
+//! # Example
+//! ```
+//! use crate::toolkit::stringsx::chunk::chunk_join;
+//!
+//! assert_eq!(chunk_join("12345678", 4, " "), "1234 5678");
+//! ```
+
+use alloc::{string::String, vec::Vec};
+
+/// Splits a string into chunks of `size` characters.
+///
+/// The last chunk holds the remaining characters and may be shorter. A `size` of zero is
+/// treated as one.
+///
+/// # Arguments
+///
+/// * `s` - The input string to split
+/// * `size` - The number of characters per chunk
+///
+/// # Returns
+///
+/// The chunks as string slices, in order. Empty if `s` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::chunk::chunk_chars;
+///
+/// assert_eq!(chunk_chars("abcdefg", 3), vec!["abc", "def", "g"]);
+/// ```
+pub fn chunk_chars(s: &str, size: usize) -> Vec<&str> {
+    let size = size.max(1);
+    let mut chunks = Vec::with_capacity(s.len().div_ceil(size));
+    let mut start = 0;
+    // Each chunk ends at the byte offset of every `size`-th character
+    for (idx, _) in s.char_indices().step_by(size).skip(1) {
+        chunks.push(&s[start..idx]);
+        start = idx;
+    }
+    if start < s.len() {
+        chunks.push(&s[start..]);
+    }
+    chunks
+}
+
+/// Splits a string into chunks of `size` characters and joins them with a separator.
+///
+/// Chunks are formed as in [`chunk_chars`].
+///
+/// # Arguments
+///
+/// * `s` - The input string to group
+/// * `size` - The number of characters per chunk
+/// * `sep` - The separator placed between chunks
+///
+/// # Returns
+///
+/// A new string with `sep` inserted after every `size` characters, except at the end.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::chunk::chunk_join;
+///
+/// assert_eq!(chunk_join("deadbeef", 2, ":"), "de:ad:be:ef");
+/// ```
+pub fn chunk_join(s: &str, size: usize, sep: &str) -> String {
+    chunk_chars(s, size).join(sep)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_chars() {
+        assert_eq!(chunk_chars("abcdef", 2), vec!["ab", "cd", "ef"]);
+        assert_eq!(chunk_chars("abcdefg", 3), vec!["abc", "def", "g"]);
+        assert_eq!(chunk_chars("abc", 10), vec!["abc"]);
+        assert_eq!(chunk_chars("", 3), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_chunk_chars_multibyte() {
+        assert_eq!(chunk_chars("héllo wörld", 4), vec!["héll", "o wö", "rld"]);
+        assert_eq!(chunk_chars("🚧🚀🎉", 2), vec!["🚧🚀", "🎉"]);
+        assert_eq!(chunk_chars("日本語", 1), vec!["日", "本", "語"]);
+    }
+
+    #[test]
+    fn test_chunk_chars_zero_size() {
+        assert_eq!(chunk_chars("abc", 0), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_chunk_join() {
+        assert_eq!(chunk_join("12345678", 4, " "), "1234 5678");
+        assert_eq!(chunk_join("1234567", 3, ","), "123,456,7");
+        assert_eq!(chunk_join("ñandú", 2, "-"), "ña-nd-ú");
+        assert_eq!(chunk_join("", 2, "-"), "");
+    }
+}
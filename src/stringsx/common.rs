@@ -0,0 +1,134 @@
+//! Provides shared prefix and suffix detection for strings.
+//!
+//! Strings are compared char by char, so results always end on char boundaries and never
+//! split multibyte characters.
+//!
+//! # Example
+//! ```
+//! use crate::toolkit::stringsx::common::common_prefix;
+//!
+//! assert_eq!(common_prefix("interspecies", "interstellar"), "inters");
+//! ```
+
+/// Returns the longest leading slice shared by two strings.
+///
+/// # Arguments
+///
+/// * `a` - The first string, which the result borrows from
+/// * `b` - The second string
+///
+/// # Returns
+///
+/// The common prefix as a slice of `a`, or an empty string if the first chars differ.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::common::common_prefix;
+///
+/// assert_eq!(common_prefix("flower", "flow"), "flow");
+/// assert_eq!(common_prefix("dog", "car"), "");
+/// ```
+pub fn common_prefix<'a>(a: &'a str, b: &str) -> &'a str {
+    let len = a
+        .chars()
+        .zip(b.chars())
+        .take_while(|(x, y)| x == y)
+        .map(|(x, _)| x.len_utf8())
+        .sum();
+    &a[..len]
+}
+
+/// Returns the longest trailing slice shared by two strings.
+///
+/// # Arguments
+///
+/// * `a` - The first string, which the result borrows from
+/// * `b` - The second string
+///
+/// # Returns
+///
+/// The common suffix as a slice of `a`, or an empty string if the last chars differ.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::common::common_suffix;
+///
+/// assert_eq!(common_suffix("walking", "talking"), "alking");
+/// ```
+pub fn common_suffix<'a>(a: &'a str, b: &str) -> &'a str {
+    let len: usize = a
+        .chars()
+        .rev()
+        .zip(b.chars().rev())
+        .take_while(|(x, y)| x == y)
+        .map(|(x, _)| x.len_utf8())
+        .sum();
+    &a[a.len() - len..]
+}
+
+/// Returns the longest leading slice shared by every string in a slice.
+///
+/// # Arguments
+///
+/// * `strings` - The strings to compare
+///
+/// # Returns
+///
+/// The common prefix as a slice of the first string, or an empty string if `strings` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::common::common_prefix_all;
+///
+/// assert_eq!(common_prefix_all(&["src/a.rs", "src/b.rs", "src/c/d.rs"]), "src/");
+/// ```
+pub fn common_prefix_all<'a>(strings: &[&'a str]) -> &'a str {
+    match strings.split_first() {
+        Some((first, rest)) => rest
+            .iter()
+            .fold(*first, |prefix, s| common_prefix(prefix, s)),
+        None => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_common_prefix() {
+        assert_eq!(common_prefix("interspecies", "interstellar"), "inters");
+        assert_eq!(common_prefix("same", "same"), "same");
+        assert_eq!(common_prefix("", "abc"), "");
+        assert_eq!(common_prefix("abc", "xyz"), "");
+    }
+
+    #[test]
+    fn test_common_prefix_multibyte() {
+        assert_eq!(common_prefix("🚧ab", "🚧ac"), "🚧a");
+        // 'é' and 'è' share their first UTF-8 byte, which must not be returned
+        assert_eq!(common_prefix("é", "è"), "");
+    }
+
+    #[test]
+    fn test_common_suffix() {
+        assert_eq!(common_suffix("walking", "talking"), "alking");
+        assert_eq!(common_suffix("abc", "abd"), "");
+        assert_eq!(common_suffix("a🚧", "b🚧"), "🚧");
+        assert_eq!(common_suffix("ä", "ã"), "");
+    }
+
+    #[test]
+    fn test_common_prefix_all() {
+        assert_eq!(
+            common_prefix_all(&["interspecies", "interstellar", "interstate"]),
+            "inters"
+        );
+        assert_eq!(common_prefix_all(&["only"]), "only");
+        assert_eq!(common_prefix_all(&["abc", "", "abd"]), "");
+        assert_eq!(common_prefix_all(&[]), "");
+    }
+}
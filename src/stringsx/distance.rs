@@ -0,0 +1,121 @@
+//! Provides edit distance utilities for fuzzy matching.
+//!
+//! Distances are counted in characters rather than bytes, so a multi-byte character counts
+//! as a single edit.
+//!
+//! # Example
+//! ```
+//! use crate::toolkit::stringsx::distance::closest;
+//!
+//! let commands = ["build", "check", "test"];
+//! assert_eq!(closest("tset", &commands), Some("test"));
+//! ```
+
+use alloc::vec::Vec;
+
+/// Computes the Levenshtein distance between two strings.
+///
+/// The distance is the minimum number of single-character insertions, deletions and
+/// substitutions needed to turn `a` into `b`.
+///
+/// # Arguments
+///
+/// * `a` - The first string
+/// * `b` - The second string
+///
+/// # Returns
+///
+/// The edit distance, `0` if the strings are equal.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::distance::levenshtein;
+///
+/// assert_eq!(levenshtein("kitten", "sitting"), 3);
+/// assert_eq!(levenshtein("same", "same"), 0);
+/// ```
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    // Distances from the processed prefix of `a` to every prefix of `b`
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &b_char) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a_char != b_char);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Finds the candidate nearest to a target string.
+///
+/// Candidates are ranked by [`levenshtein`] distance to `target`. When several candidates are
+/// equally near, the first of them is returned.
+///
+/// # Arguments
+///
+/// * `target` - The string to match, such as a mistyped command
+/// * `candidates` - The strings to choose from
+///
+/// # Returns
+///
+/// The nearest candidate, or `None` if `candidates` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::distance::closest;
+///
+/// assert_eq!(closest("colour", &["color", "column"]), Some("color"));
+/// assert_eq!(closest("anything", &[]), None);
+/// ```
+pub fn closest<'a>(target: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .copied()
+        .min_by_key(|candidate| levenshtein(target, candidate))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("sitting", "kitten"), 3);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(levenshtein("same", "same"), 0);
+    }
+
+    #[test]
+    fn test_levenshtein_empty() {
+        assert_eq!(levenshtein("", ""), 0);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("abc", ""), 3);
+    }
+
+    #[test]
+    fn test_levenshtein_counts_chars() {
+        assert_eq!(levenshtein("café", "cafe"), 1);
+        assert_eq!(levenshtein("🚧", "🚀"), 1);
+    }
+
+    #[test]
+    fn test_closest() {
+        let commands = ["install", "uninstall", "update", "upgrade"];
+        assert_eq!(closest("instal", &commands), Some("install"));
+        assert_eq!(closest("upgarde", &commands), Some("upgrade"));
+        assert_eq!(closest("x", &[]), None);
+    }
+
+    #[test]
+    fn test_closest_tie_keeps_first() {
+        assert_eq!(closest("cat", &["bat", "hat"]), Some("bat"));
+    }
+}
@@ -0,0 +1,125 @@
+//! Provides indentation utilities for multi-line strings.
+//!
+//! Lines are split on `\n` and keep their original line endings. A line is blank when it
+//! contains only whitespace.
+//!
+//! # Example
+//! ```
+//! use crate::toolkit::stringsx::indent::{dedent, indent};
+//!
+//! let block = "fn main() {\n    run();\n}";
+//! assert_eq!(dedent(&indent(block, "  ")), block);
+//! ```
+
+use alloc::string::String;
+
+use crate::stringsx::common::common_prefix;
+
+/// Returns whether a line holds nothing but whitespace
+fn is_blank(line: &str) -> bool {
+    line.chars().all(char::is_whitespace)
+}
+
+/// Returns the leading spaces and tabs of a line
+fn leading_indent(line: &str) -> &str {
+    let content = line.trim_start_matches([' ', '\t']);
+    &line[..line.len() - content.len()]
+}
+
+/// Prepends a prefix to every non-blank line of a string.
+///
+/// Blank lines are left untouched so that no trailing whitespace is introduced.
+///
+/// # Arguments
+///
+/// * `s` - The input text
+/// * `prefix` - The string to prepend to each line, typically spaces
+///
+/// # Returns
+///
+/// A new string with every non-blank line prefixed.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::indent::indent;
+///
+/// assert_eq!(indent("a\n\nb", "> "), "> a\n\n> b");
+/// ```
+pub fn indent(s: &str, prefix: &str) -> String {
+    let mut indented = String::with_capacity(s.len());
+    for line in s.split_inclusive('\n') {
+        if !is_blank(line) {
+            indented.push_str(prefix);
+        }
+        indented.push_str(line);
+    }
+    indented
+}
+
+/// Removes the longest leading whitespace shared by all non-blank lines of a string.
+///
+/// Spaces and tabs are compared literally, so a tab never matches spaces. Blank lines do not
+/// affect the common indentation and are reduced to their line ending.
+///
+/// # Arguments
+///
+/// * `s` - The input text
+///
+/// # Returns
+///
+/// A new string with the common indentation removed.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::indent::dedent;
+///
+/// assert_eq!(dedent("    a\n      b\n    c"), "a\n  b\nc");
+/// ```
+pub fn dedent(s: &str) -> String {
+    let common = s
+        .split_inclusive('\n')
+        .filter(|line| !is_blank(line))
+        .map(leading_indent)
+        .reduce(common_prefix)
+        .unwrap_or("");
+
+    let mut dedented = String::with_capacity(s.len());
+    for line in s.split_inclusive('\n') {
+        if is_blank(line) {
+            dedented.push_str(&line[line.trim_end_matches(['\r', '\n']).len()..]);
+        } else {
+            dedented.push_str(&line[common.len()..]);
+        }
+    }
+    dedented
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indent() {
+        assert_eq!(indent("one\ntwo\nthree", "  "), "  one\n  two\n  three");
+        assert_eq!(indent("one\n\n  \nthree\n", "> "), "> one\n\n  \n> three\n");
+        assert_eq!(indent("", "  "), "");
+        assert_eq!(indent("a\r\nb", "-"), "-a\r\n-b");
+    }
+
+    #[test]
+    fn test_dedent() {
+        assert_eq!(dedent("    one\n      two\n    three"), "one\n  two\nthree");
+        assert_eq!(dedent("  one\n\n  three\n"), "one\n\nthree\n");
+        assert_eq!(dedent("  one\n    \n  three"), "one\n\nthree");
+        assert_eq!(dedent("no indent\n  here"), "no indent\n  here");
+        assert_eq!(dedent(""), "");
+    }
+
+    #[test]
+    fn test_dedent_mixed_tabs_and_spaces() {
+        assert_eq!(dedent("\t  a\n\t  b"), "a\nb");
+        assert_eq!(dedent("\ta\n  b"), "\ta\n  b");
+    }
+}
@@ -0,0 +1,113 @@
+//! Provides string length measurements beyond byte length.
+//!
+//! Grapheme counting and terminal display width are available with the `unicode` feature.
+
+#[cfg(feature = "unicode")]
+use unicode_segmentation::UnicodeSegmentation;
+#[cfg(feature = "unicode")]
+use unicode_width::UnicodeWidthStr;
+
+/// Counts the Unicode scalar values (chars) in a string.
+///
+/// This is the unit used by the character-based truncation functions.
+///
+/// # Arguments
+///
+/// * `s` - The input string to measure
+///
+/// # Returns
+///
+/// The number of chars in the string.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::len::char_len;
+///
+/// assert_eq!(char_len("héllo"), 5);
+/// assert_eq!("héllo".len(), 6);
+/// ```
+pub fn char_len(s: &str) -> usize {
+    s.chars().count()
+}
+
+/// Counts the extended grapheme clusters, or user-perceived characters, in a string.
+///
+/// # Arguments
+///
+/// * `s` - The input string to measure
+///
+/// # Returns
+///
+/// The number of grapheme clusters in the string.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::len::grapheme_len;
+///
+/// assert_eq!(grapheme_len("ne\u{301}e"), 3);
+/// ```
+#[cfg(feature = "unicode")]
+pub fn grapheme_len(s: &str) -> usize {
+    s.graphemes(true).count()
+}
+
+/// Measures the number of terminal columns a string occupies.
+///
+/// East Asian wide characters and most emoji take two columns, while combining marks and
+/// zero-width characters take none. Useful for aligning output in a terminal.
+///
+/// # Arguments
+///
+/// * `s` - The input string to measure
+///
+/// # Returns
+///
+/// The display width of the string in columns.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::len::display_width;
+///
+/// assert_eq!(display_width("abc"), 3);
+/// assert_eq!(display_width("日本"), 4);
+/// ```
+#[cfg(feature = "unicode")]
+pub fn display_width(s: &str) -> usize {
+    s.width()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_char_len() {
+        assert_eq!(char_len(""), 0);
+        assert_eq!(char_len("Hello"), 5);
+        assert_eq!(char_len("🚧🚧"), 2);
+        assert_eq!("🚧🚧".len(), 8);
+        assert_eq!(char_len("héllo"), 5);
+        assert_eq!("héllo".len(), 6);
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_grapheme_len() {
+        let family = "👨\u{200d}👩\u{200d}👧";
+        assert_eq!(grapheme_len(family), 1);
+        assert_eq!(char_len(family), 5);
+        assert_eq!(grapheme_len("e\u{301}"), 1);
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_display_width() {
+        assert_eq!(display_width(""), 0);
+        assert_eq!(display_width("Hello"), 5);
+        assert_eq!(display_width("日本語"), 6);
+        assert_eq!(display_width("e\u{301}"), 1);
+    }
+}
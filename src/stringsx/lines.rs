@@ -0,0 +1,109 @@
+//! Provides line splitting utilities for mixed line endings.
+//!
+//! Lines may end with `\n`, `\r\n` or a lone `\r`, in any mix. As with [`str::lines`], a
+//! terminator at the very end of the input does not start an extra empty line.
+
+use alloc::vec::Vec;
+
+/// Splits a string into lines, each slice including its line terminator
+fn lines_with_ends(s: &str) -> Vec<&str> {
+    let bytes = s.as_bytes();
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let end = match bytes[idx] {
+            b'\r' if bytes.get(idx + 1) == Some(&b'\n') => idx + 2,
+            b'\r' | b'\n' => idx + 1,
+            _ => {
+                idx += 1;
+                continue;
+            }
+        };
+        lines.push(&s[start..end]);
+        start = end;
+        idx = end;
+    }
+    if start < s.len() {
+        lines.push(&s[start..]);
+    }
+    lines
+}
+
+/// Splits a string into lines on `\n`, `\r\n` and `\r`, dropping the terminators.
+///
+/// # Arguments
+///
+/// * `s` - The input string to split
+///
+/// # Returns
+///
+/// A vector of line slices without their line terminators. Empty input yields an empty vector.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::lines::split_lines;
+///
+/// assert_eq!(split_lines("one\r\ntwo\nthree\rfour"), vec!["one", "two", "three", "four"]);
+/// ```
+pub fn split_lines(s: &str) -> Vec<&str> {
+    lines_with_ends(s)
+        .into_iter()
+        .map(|line| line.trim_end_matches(['\r', '\n']))
+        .collect()
+}
+
+/// Splits a string into lines on `\n`, `\r\n` and `\r`, keeping the terminators.
+///
+/// Concatenating the returned slices reproduces the input exactly.
+///
+/// # Arguments
+///
+/// * `s` - The input string to split
+///
+/// # Returns
+///
+/// A vector of line slices, each ending with its original terminator except possibly the last.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::lines::split_lines_keep_ends;
+///
+/// assert_eq!(split_lines_keep_ends("one\r\ntwo"), vec!["one\r\n", "two"]);
+/// ```
+pub fn split_lines_keep_ends(s: &str) -> Vec<&str> {
+    lines_with_ends(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_lines_mixed() {
+        assert_eq!(split_lines("a\r\nb\nc\r\nd"), ["a", "b", "c", "d"]);
+        assert_eq!(split_lines("a\rb\n\nc"), ["a", "b", "", "c"]);
+        assert_eq!(split_lines("a\n\r\nb"), ["a", "", "b"]);
+        assert_eq!(split_lines("a\n\rb"), ["a", "", "b"]);
+    }
+
+    #[test]
+    fn test_split_lines_edges() {
+        assert!(split_lines("").is_empty());
+        assert_eq!(split_lines("single"), ["single"]);
+        assert_eq!(split_lines("trailing\r\n"), ["trailing"]);
+        assert_eq!(split_lines("\n"), [""]);
+        assert_eq!(split_lines("🚧\r🚧"), ["🚧", "🚧"]);
+    }
+
+    #[test]
+    fn test_split_lines_keep_ends() {
+        let input = "a\r\nb\nc\rd\r\n";
+        let lines = split_lines_keep_ends(input);
+        assert_eq!(lines, ["a\r\n", "b\n", "c\r", "d\r\n"]);
+        assert_eq!(lines.concat(), input);
+        assert!(split_lines_keep_ends("").is_empty());
+    }
+}
@@ -0,0 +1,107 @@
+//! Provides masking utilities for partially hiding sensitive strings.
+//!
+//! Masking preserves the number of characters, so the masked output has the same visual
+//! length as the input.
+//!
+//! # Example
+//! ```
+//! use crate::toolkit::stringsx::mask::mask;
+//!
+//! assert_eq!(mask("4111111111111234", 4, '•'), "••••••••••••1234");
+//! ```
+
+use alloc::string::String;
+
+/// Masks every character of a string except the last `visible_suffix` ones.
+///
+/// # Arguments
+///
+/// * `s` - The input string to mask
+/// * `visible_suffix` - The number of trailing characters left visible
+/// * `mask_char` - The character replacing each hidden character
+///
+/// # Returns
+///
+/// A new string with the same number of characters as `s`. If `visible_suffix` is at least the
+/// length of `s`, nothing is masked.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::mask::mask;
+///
+/// assert_eq!(mask("secret", 2, '*'), "****et");
+/// ```
+pub fn mask(s: &str, visible_suffix: usize, mask_char: char) -> String {
+    let hidden = s.chars().count().saturating_sub(visible_suffix);
+    s.chars()
+        .enumerate()
+        .map(|(idx, c)| if idx < hidden { mask_char } else { c })
+        .collect()
+}
+
+/// Masks the local part of an email address except its first character.
+///
+/// The domain after the last `@` stays visible. Input without an `@` is masked entirely.
+///
+/// # Arguments
+///
+/// * `email` - The email address to mask
+/// * `mask_char` - The character replacing each hidden character
+///
+/// # Returns
+///
+/// A new string with the same number of characters as `email`.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::mask::mask_email;
+///
+/// assert_eq!(mask_email("john.doe@example.com", '*'), "j*******@example.com");
+/// ```
+pub fn mask_email(email: &str, mask_char: char) -> String {
+    let Some((local, domain)) = email.rsplit_once('@') else {
+        return mask(email, 0, mask_char);
+    };
+
+    let mut masked = String::with_capacity(email.len());
+    let mut chars = local.chars();
+    masked.extend(chars.next());
+    masked.extend(chars.map(|_| mask_char));
+    masked.push('@');
+    masked.push_str(domain);
+    masked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mask_card_number() {
+        let masked = mask("4111111111111234", 4, '•');
+        assert_eq!(masked, "••••••••••••1234");
+        assert_eq!(masked.chars().count(), 16);
+    }
+
+    #[test]
+    fn test_mask_edges() {
+        assert_eq!(mask("abc", 5, '*'), "abc");
+        assert_eq!(mask("abc", 0, '*'), "***");
+        assert_eq!(mask("", 2, '*'), "");
+        assert_eq!(mask("héllo🚧", 1, '*'), "*****🚧");
+    }
+
+    #[test]
+    fn test_mask_email() {
+        assert_eq!(
+            mask_email("john.doe@example.com", '*'),
+            "j*******@example.com"
+        );
+        assert_eq!(mask_email("a@example.com", '*'), "a@example.com");
+        assert_eq!(mask_email("\"a@b\"@example.com", '*'), "\"****@example.com");
+        assert_eq!(mask_email("not-an-email", '*'), "************");
+        assert_eq!(mask_email("@example.com", '*'), "@example.com");
+    }
+}
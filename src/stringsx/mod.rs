@@ -1,4 +1,30 @@
+//! String utilities
+//!
+//! Everything in this module except [`template`] only depends on `core` and `alloc`, so it is
+//! available without the `std` feature.
+
+pub mod affix;
+pub mod ansi;
+pub mod build;
 pub mod case;
+pub mod chunk;
 pub mod coalesce;
+pub mod common;
+pub mod distance;
+pub mod indent;
+pub mod len;
+pub mod lines;
+pub mod mask;
+pub mod pad;
+pub mod plural;
+pub mod reverse;
+pub mod search;
+pub mod slug;
 pub mod split;
+#[cfg(feature = "std")]
+pub mod template;
+pub mod trim;
 pub mod truncate;
+pub mod whitespace;
+pub mod words;
+pub mod wrap;
@@ -0,0 +1,165 @@
+//! String padding utilities
+//!
+//! This module provides functions for padding strings to a fixed width, measured in
+//! characters, which is useful for aligning tabular output.
+//! Functions include:
+//! - `pad_left`: Right-align a string by padding on the left
+//! - `pad_right`: Left-align a string by padding on the right
+//! - `pad_center`: Center a string by padding on both sides
+//! - `pad_left_spaces`, `pad_right_spaces`, `pad_center_spaces`: The same, padding with spaces
+//!
+//! # Example
+//! ```
+//! use crate::toolkit::stringsx::pad::{pad_center, pad_left_spaces};
+//!
+//! assert_eq!(pad_left_spaces("42", 5), "   42");
+//! assert_eq!(pad_center("ok", 6, '*'), "**ok**");
+//! ```
+
+use alloc::string::{String, ToString};
+
+/// Builds a string of `left` fill characters, the input, then `right` fill characters
+fn pad_with(s: &str, left: usize, right: usize, fill: char) -> String {
+    let mut padded = String::with_capacity(s.len() + (left + right) * fill.len_utf8());
+    padded.extend(core::iter::repeat_n(fill, left));
+    padded.push_str(s);
+    padded.extend(core::iter::repeat_n(fill, right));
+    padded
+}
+
+/// Returns how many fill characters are needed to reach `width`, if any
+fn missing_chars(s: &str, width: usize) -> Option<usize> {
+    width.checked_sub(s.chars().count()).filter(|&n| n > 0)
+}
+
+/// Pads a string on the left to a width in characters
+///
+/// # Arguments
+/// * `s` - The input string to pad
+/// * `width` - The minimum number of characters in the output
+/// * `fill` - The character to pad with
+///
+/// # Returns
+/// * A new string right-aligned to `width`, or the input unchanged if it is already at least
+///   `width` characters wide
+///
+/// # Examples
+/// ```
+/// use crate::toolkit::stringsx::pad::pad_left;
+///
+/// assert_eq!(pad_left("7", 3, '0'), "007");
+/// ```
+pub fn pad_left(s: &str, width: usize, fill: char) -> String {
+    match missing_chars(s, width) {
+        Some(n) => pad_with(s, n, 0, fill),
+        None => s.to_string(),
+    }
+}
+
+/// Pads a string on the right to a width in characters
+///
+/// # Arguments
+/// * `s` - The input string to pad
+/// * `width` - The minimum number of characters in the output
+/// * `fill` - The character to pad with
+///
+/// # Returns
+/// * A new string left-aligned to `width`, or the input unchanged if it is already at least
+///   `width` characters wide
+///
+/// # Examples
+/// ```
+/// use crate::toolkit::stringsx::pad::pad_right;
+///
+/// assert_eq!(pad_right("name", 6, '.'), "name..");
+/// ```
+pub fn pad_right(s: &str, width: usize, fill: char) -> String {
+    match missing_chars(s, width) {
+        Some(n) => pad_with(s, 0, n, fill),
+        None => s.to_string(),
+    }
+}
+
+/// Pads a string on both sides to center it within a width in characters
+///
+/// When the padding cannot be split evenly, the extra fill character goes on the right.
+///
+/// # Arguments
+/// * `s` - The input string to pad
+/// * `width` - The minimum number of characters in the output
+/// * `fill` - The character to pad with
+///
+/// # Returns
+/// * A new string centered within `width`, or the input unchanged if it is already at least
+///   `width` characters wide
+///
+/// # Examples
+/// ```
+/// use crate::toolkit::stringsx::pad::pad_center;
+///
+/// assert_eq!(pad_center("ab", 5, '-'), "-ab--");
+/// ```
+pub fn pad_center(s: &str, width: usize, fill: char) -> String {
+    match missing_chars(s, width) {
+        Some(n) => pad_with(s, n / 2, n - n / 2, fill),
+        None => s.to_string(),
+    }
+}
+
+/// Pads a string on the left with spaces, see [`pad_left`]
+pub fn pad_left_spaces(s: &str, width: usize) -> String {
+    pad_left(s, width, ' ')
+}
+
+/// Pads a string on the right with spaces, see [`pad_right`]
+pub fn pad_right_spaces(s: &str, width: usize) -> String {
+    pad_right(s, width, ' ')
+}
+
+/// Centers a string with spaces, see [`pad_center`]
+pub fn pad_center_spaces(s: &str, width: usize) -> String {
+    pad_center(s, width, ' ')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pad_left() {
+        assert_eq!(pad_left("7", 3, '0'), "007");
+        assert_eq!(pad_left("", 2, '-'), "--");
+        assert_eq!(pad_left_spaces("ab", 4), "  ab");
+    }
+
+    #[test]
+    fn test_pad_right() {
+        assert_eq!(pad_right("name", 6, '.'), "name..");
+        assert_eq!(pad_right_spaces("ab", 4), "ab  ");
+    }
+
+    #[test]
+    fn test_pad_center_even() {
+        assert_eq!(pad_center("ok", 6, '*'), "**ok**");
+        assert_eq!(pad_center_spaces("ab", 4), " ab ");
+    }
+
+    #[test]
+    fn test_pad_center_odd() {
+        assert_eq!(pad_center("ab", 5, '-'), "-ab--");
+        assert_eq!(pad_center("a", 2, '-'), "a-");
+    }
+
+    #[test]
+    fn test_pad_counts_chars() {
+        assert_eq!(pad_left("🚧", 3, '·'), "··🚧");
+        assert_eq!(pad_right("héllo", 6, '-'), "héllo-");
+    }
+
+    #[test]
+    fn test_pad_wider_than_width() {
+        assert_eq!(pad_left("Hello", 3, ' '), "Hello");
+        assert_eq!(pad_right("Hello", 5, ' '), "Hello");
+        assert_eq!(pad_center("Hello", 0, ' '), "Hello");
+    }
+}
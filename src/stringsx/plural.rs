@@ -0,0 +1,164 @@
+//! Provides English pluralization helpers for user-facing messages.
+//!
+//! The rules are deliberately basic and cover regular nouns only. Irregular nouns such as
+//! `child` or `mouse` need their plural form given explicitly.
+//!
+//! # Example
+//! ```
+//! use crate::toolkit::stringsx::plural::{count_noun, pluralize};
+//!
+//! assert_eq!(format!("Deleted 3 {}", pluralize("file", 3)), "Deleted 3 files");
+//! assert_eq!(count_noun(1, "item", "items"), "1 item");
+//! ```
+
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+/// Returns the plural of a regular English noun, unless the count is exactly one.
+///
+/// Words ending in `s`, `x`, `z`, `ch` or `sh` get `es`, words ending in a consonant followed
+/// by `y` replace the `y` with `ies`, and all other words get `s`. The suffix is uppercase when
+/// the word ends with an uppercase letter.
+///
+/// # Arguments
+///
+/// * `word` - The singular noun
+/// * `count` - The number of items the noun refers to
+///
+/// # Returns
+///
+/// The word unchanged if `count` is 1, its plural otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::plural::pluralize;
+///
+/// assert_eq!(pluralize("box", 2), "boxes");
+/// assert_eq!(pluralize("city", 0), "cities");
+/// assert_eq!(pluralize("day", 5), "days");
+/// assert_eq!(pluralize("box", 1), "box");
+/// ```
+pub fn pluralize(word: &str, count: usize) -> String {
+    if count == 1 || word.is_empty() {
+        return word.to_string();
+    }
+
+    let lower = word.to_ascii_lowercase();
+    let (stem, suffix) = if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|ending| lower.ends_with(ending))
+    {
+        (word, "es")
+    } else if lower.ends_with('y') && !lower[..lower.len() - 1].ends_with(['a', 'e', 'i', 'o', 'u'])
+    {
+        (&word[..word.len() - 1], "ies")
+    } else {
+        (word, "s")
+    };
+
+    if word.ends_with(|c: char| c.is_uppercase()) {
+        format!("{}{}", stem, suffix.to_ascii_uppercase())
+    } else {
+        format!("{}{}", stem, suffix)
+    }
+}
+
+/// Chooses between a singular and an explicit plural form based on a count.
+///
+/// Use this for irregular nouns that [`pluralize`] gets wrong.
+///
+/// # Arguments
+///
+/// * `word` - The singular noun
+/// * `count` - The number of items the noun refers to
+/// * `plural_form` - The plural noun
+///
+/// # Returns
+///
+/// The word if `count` is 1, `plural_form` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::plural::pluralize_with;
+///
+/// assert_eq!(pluralize_with("child", 3, "children"), "children");
+/// ```
+pub fn pluralize_with(word: &str, count: usize, plural_form: &str) -> String {
+    if count == 1 {
+        word.to_string()
+    } else {
+        plural_form.to_string()
+    }
+}
+
+/// Formats a count followed by the matching singular or plural noun.
+///
+/// # Arguments
+///
+/// * `count` - The number of items
+/// * `singular` - The noun used when `count` is 1
+/// * `plural` - The noun used otherwise
+///
+/// # Returns
+///
+/// A new string such as `"1 item"` or `"3 items"`.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::plural::count_noun;
+///
+/// assert_eq!(count_noun(3, "item", "items"), "3 items");
+/// assert_eq!(count_noun(0, "mouse", "mice"), "0 mice");
+/// ```
+pub fn count_noun(count: usize, singular: &str, plural: &str) -> String {
+    format!("{} {}", count, pluralize_with(singular, count, plural))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pluralize_regular() {
+        assert_eq!(pluralize("item", 2), "items");
+        assert_eq!(pluralize("box", 2), "boxes");
+        assert_eq!(pluralize("bus", 2), "buses");
+        assert_eq!(pluralize("church", 2), "churches");
+        assert_eq!(pluralize("dish", 2), "dishes");
+        assert_eq!(pluralize("buzz", 2), "buzzes");
+    }
+
+    #[test]
+    fn test_pluralize_y() {
+        assert_eq!(pluralize("city", 2), "cities");
+        assert_eq!(pluralize("day", 2), "days");
+        assert_eq!(pluralize("key", 2), "keys");
+        assert_eq!(pluralize("CITY", 2), "CITIES");
+    }
+
+    #[test]
+    fn test_pluralize_count() {
+        assert_eq!(pluralize("box", 1), "box");
+        assert_eq!(pluralize("box", 0), "boxes");
+        assert_eq!(pluralize("", 2), "");
+    }
+
+    #[test]
+    fn test_pluralize_with() {
+        assert_eq!(pluralize_with("mouse", 2, "mice"), "mice");
+        assert_eq!(pluralize_with("mouse", 1, "mice"), "mouse");
+        assert_eq!(pluralize_with("sheep", 4, "sheep"), "sheep");
+    }
+
+    #[test]
+    fn test_count_noun() {
+        assert_eq!(count_noun(1, "item", "items"), "1 item");
+        assert_eq!(count_noun(3, "item", "items"), "3 items");
+        assert_eq!(count_noun(2, "person", "people"), "2 people");
+    }
+}
@@ -0,0 +1,79 @@
+//! Provides string reversal utilities.
+//!
+//! Grapheme-cluster aware reversal is available with the `unicode` feature.
+
+use alloc::string::String;
+
+#[cfg(feature = "unicode")]
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Reverses a string by Unicode scalar values (chars).
+///
+/// Multibyte characters stay valid, but clusters made of several chars, such as emoji joined
+/// with zero-width joiners or letters followed by combining marks, are split apart. Use
+/// `reverse_graphemes` with the `unicode` feature for user-visible text.
+///
+/// # Arguments
+///
+/// * `s` - The input string to reverse
+///
+/// # Returns
+///
+/// A new string with the chars in reverse order.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::reverse::reverse_chars;
+///
+/// assert_eq!(reverse_chars("héllo"), "olléh");
+/// ```
+pub fn reverse_chars(s: &str) -> String {
+    s.chars().rev().collect()
+}
+
+/// Reverses a string by extended grapheme clusters.
+///
+/// Each user-perceived character is kept intact, so emoji sequences and combining marks
+/// survive the reversal.
+///
+/// # Arguments
+///
+/// * `s` - The input string to reverse
+///
+/// # Returns
+///
+/// A new string with the grapheme clusters in reverse order.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::reverse::reverse_graphemes;
+///
+/// assert_eq!(reverse_graphemes("ne\u{301}e"), "ee\u{301}n");
+/// ```
+#[cfg(feature = "unicode")]
+pub fn reverse_graphemes(s: &str) -> String {
+    s.graphemes(true).rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reverse_chars() {
+        assert_eq!(reverse_chars("Hello"), "olleH");
+        assert_eq!(reverse_chars("a🚧b"), "b🚧a");
+        assert_eq!(reverse_chars(""), "");
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_reverse_graphemes() {
+        let family = "👨\u{200d}👩\u{200d}👧";
+        let reversed = reverse_graphemes(&alloc::format!("ab{}cd", family));
+        assert_eq!(reversed, alloc::format!("dc{}ba", family));
+        assert_eq!(reverse_graphemes(""), "");
+    }
+}
@@ -0,0 +1,92 @@
+//! Provides case-insensitive substring search without allocation.
+//!
+//! Comparisons ignore ASCII case only, non-ASCII characters must match exactly.
+//!
+//! # Example
+//! ```
+//! use crate::toolkit::stringsx::search::contains_ci;
+//!
+//! assert!(contains_ci("Content-Type: Application/JSON", "application/json"));
+//! ```
+
+/// Finds the first ASCII-case-insensitive occurrence of a needle in a haystack.
+///
+/// # Arguments
+///
+/// * `haystack` - The string to search in
+/// * `needle` - The string to search for
+///
+/// # Returns
+///
+/// The byte offset of the first match, which always lies on a char boundary, or `None` if there
+/// is no match. An empty needle matches at offset 0.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::search::find_ci;
+///
+/// assert_eq!(find_ci("Hello World", "WORLD"), Some(6));
+/// assert_eq!(find_ci("Hello World", "planet"), None);
+/// ```
+pub fn find_ci(haystack: &str, needle: &str) -> Option<usize> {
+    let needle = needle.as_bytes();
+    let last_start = haystack.len().checked_sub(needle.len())?;
+    haystack
+        .char_indices()
+        .map(|(idx, _)| idx)
+        .chain(core::iter::once(haystack.len()))
+        .take_while(|&idx| idx <= last_start)
+        .find(|&idx| haystack.as_bytes()[idx..idx + needle.len()].eq_ignore_ascii_case(needle))
+}
+
+/// Checks whether a haystack contains a needle, ignoring ASCII case.
+///
+/// # Arguments
+///
+/// * `haystack` - The string to search in
+/// * `needle` - The string to search for
+///
+/// # Returns
+///
+/// `true` if [`find_ci`] finds a match, `false` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::search::contains_ci;
+///
+/// assert!(contains_ci("Hello World", "hello"));
+/// ```
+pub fn contains_ci(haystack: &str, needle: &str) -> bool {
+    find_ci(haystack, needle).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_ci() {
+        assert_eq!(find_ci("Hello World", "world"), Some(6));
+        assert_eq!(find_ci("hello world", "WORLD"), Some(6));
+        assert_eq!(find_ci("abcABC", "ABC"), Some(0));
+        assert_eq!(find_ci("abc", ""), Some(0));
+        assert_eq!(find_ci("", ""), Some(0));
+        assert_eq!(find_ci("ab", "abc"), None);
+    }
+
+    #[test]
+    fn test_find_ci_multibyte() {
+        assert_eq!(find_ci("🚧 Rust 🚧", "rust"), Some(5));
+        assert_eq!(find_ci("Über", "über"), None);
+        assert_eq!(find_ci("naïve NAÏVE", "naÏve"), Some(7));
+    }
+
+    #[test]
+    fn test_contains_ci() {
+        assert!(contains_ci("Bearer TOKEN", "bearer token"));
+        assert!(!contains_ci("Bearer TOKEN", "basic"));
+        assert!(!contains_ci("", "a"));
+    }
+}
@@ -0,0 +1,125 @@
+//! Provides URL slug generation.
+//!
+//! Slugs are lowercased, sanitized to ASCII letters and digits separated by single hyphens,
+//! and shortened with the character truncation in [`crate::stringsx::truncate`].
+
+use alloc::string::{String, ToString};
+
+use crate::stringsx::truncate::truncate_char_len;
+
+/// Returns the ASCII transliteration of a lowercase Latin letter with a diacritic or ligature
+fn transliterate(c: char) -> Option<&'static str> {
+    let ascii = match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'ç' | 'ć' | 'č' => "c",
+        'ď' | 'đ' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+        'ğ' => "g",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' => "i",
+        'ł' => "l",
+        'ñ' | 'ń' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => "o",
+        'ř' => "r",
+        'ś' | 'š' | 'ş' => "s",
+        'ť' | 'ţ' => "t",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        'ß' => "ss",
+        'æ' => "ae",
+        'œ' => "oe",
+        'þ' => "th",
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+/// Converts a string into a lowercase, hyphen-separated URL slug
+///
+/// The input is lowercased and every run of characters other than ASCII letters and digits
+/// becomes a single hyphen, with no hyphen at either end. Case changes do not start a new
+/// word, so `"iPhone"` becomes `"iphone"`. Common Latin letters with diacritics are
+/// transliterated, so `"Héllo"` becomes `"hello"`, and any other non-ASCII character acts as
+/// a separator. The slug is then truncated to `max_len` characters without leaving a trailing
+/// hyphen.
+///
+/// # Arguments
+///
+/// * `s` - The input string, such as a post title
+/// * `max_len` - The maximum number of characters in the slug
+///
+/// # Returns
+///
+/// * A new String containing only ASCII lowercase letters, digits and single hyphens
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::slug::slugify;
+///
+/// assert_eq!(slugify("  Héllo, World! 123  ", 64), "hello-world-123");
+/// assert_eq!(slugify("Hello, World! 123", 8), "hello-wo");
+/// assert_eq!(slugify("Hello, World! 123", 6), "hello");
+/// ```
+pub fn slugify(s: &str, max_len: usize) -> String {
+    let mut slug = String::with_capacity(s.len());
+    let mut pending_hyphen = false;
+    for c in s.chars().flat_map(char::to_lowercase) {
+        let mut buf = [0; 4];
+        let ascii = match c {
+            'a'..='z' | '0'..='9' => &*c.encode_utf8(&mut buf),
+            _ => match transliterate(c) {
+                Some(ascii) => ascii,
+                None => {
+                    pending_hyphen = true;
+                    continue;
+                }
+            },
+        };
+        if pending_hyphen && !slug.is_empty() {
+            slug.push('-');
+        }
+        pending_hyphen = false;
+        slug.push_str(ascii);
+    }
+    truncate_char_len(&slug, max_len)
+        .trim_end_matches('-')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("  Héllo, World! 123  ", 64), "hello-world-123");
+        assert_eq!(slugify("My First Post", 64), "my-first-post");
+        assert_eq!(slugify("--a__b--", 64), "a-b");
+        assert_eq!(slugify("", 64), "");
+        assert_eq!(slugify("日本語", 64), "");
+    }
+
+    #[test]
+    fn test_slugify_keeps_camel_case_words() {
+        assert_eq!(slugify("iPhone 15", 64), "iphone-15");
+        assert_eq!(slugify("GitHub", 64), "github");
+        assert_eq!(slugify("parseHTTPResponse", 64), "parsehttpresponse");
+    }
+
+    #[test]
+    fn test_slugify_non_ascii() {
+        assert_eq!(slugify("CAFÉs", 64), "cafes");
+        assert_eq!(slugify("Straße in Zürich", 64), "strasse-in-zurich");
+        assert_eq!(slugify("Crème brûlée", 64), "creme-brulee");
+        assert_eq!(slugify("Tokyo東京Guide", 64), "tokyo-guide");
+        assert_eq!(slugify("🚧 WIP 🚧", 64), "wip");
+    }
+
+    #[test]
+    fn test_slugify_truncates_without_trailing_hyphen() {
+        assert_eq!(slugify("Hello, World! 123", 6), "hello");
+        assert_eq!(slugify("Hello, World! 123", 7), "hello-w");
+        assert_eq!(slugify("Hello", 0), "");
+    }
+}
@@ -3,6 +3,13 @@
 //! This module provides functions for splitting strings into vectors
 //! of substrings using separators.
 
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
 /// Splits a string slice into a vector of string slices using a separator
 ///
 /// # Arguments
@@ -34,6 +41,321 @@ pub fn splitx<'word>(s: &'word str, sep: &'_ str) -> Vec<&'word str> {
     s.split(sep).collect()
 }
 
+/// Splits a string slice into a vector of owned strings using a separator
+///
+/// Like [`splitx`], but the pieces do not borrow from the input, so they can be moved across
+/// threads or async tasks that outlive it.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to split
+/// * `sep` - The separator string to split on
+///
+/// # Returns
+///
+/// A vector of owned strings containing the split components, empty for an empty input
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::split::splitx_owned;
+///
+/// let result = splitx_owned("hello world", " ");
+/// assert_eq!(result, vec!["hello".to_string(), "world".to_string()]);
+/// ```
+pub fn splitx_owned(s: &str, sep: &str) -> Vec<String> {
+    splitx(s, sep)
+        .into_iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Splits a string slice using a separator and drops duplicate pieces
+///
+/// Each distinct piece is kept once, at the position of its first occurrence. Empty pieces
+/// are treated like any other, so at most one is kept.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to split
+/// * `sep` - The separator string to split on
+///
+/// # Returns
+///
+/// A vector of distinct string slices in first-occurrence order, empty for an empty input
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::split::splitx_unique;
+///
+/// let result = splitx_unique("read,write,read", ",");
+/// assert_eq!(result, vec!["read", "write"]);
+/// ```
+pub fn splitx_unique<'word>(s: &'word str, sep: &'_ str) -> Vec<&'word str> {
+    let mut seen = BTreeSet::new();
+    splitx(s, sep)
+        .into_iter()
+        .filter(|piece| seen.insert(*piece))
+        .collect()
+}
+
+/// Splits a string slice into at most `n` parts using a separator
+///
+/// The last element holds the unsplit remainder, matching the semantics of `str::splitn`.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to split
+/// * `sep` - The separator string to split on
+/// * `n` - The maximum number of parts to return
+///
+/// # Returns
+///
+/// A vector of at most `n` string slices
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::split::splitx_n;
+///
+/// let result = splitx_n("a,b,c,d", ",", 2);
+/// assert_eq!(result, vec!["a", "b,c,d"]);
+///
+/// let empty = splitx_n("", ",", 2);
+/// assert_eq!(empty, Vec::<&str>::new());
+/// ```
+pub fn splitx_n<'word>(s: &'word str, sep: &'_ str, n: usize) -> Vec<&'word str> {
+    // Return empty vector if input string is empty
+    if s.is_empty() {
+        return vec![];
+    }
+    // Split string on separator at most n times and collect into vector
+    s.splitn(n, sep).collect()
+}
+
+/// Splits a string slice using a separator and trims ASCII whitespace from each piece
+///
+/// Pieces that are empty after trimming are kept, so the number of pieces always matches
+/// [`splitx`]. Use [`splitx_trim_nonempty`] to drop them.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to split
+/// * `sep` - The separator string to split on
+///
+/// # Returns
+///
+/// A vector of trimmed string slices
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::split::splitx_trim;
+///
+/// let result = splitx_trim(" a , b ,, c ", ",");
+/// assert_eq!(result, vec!["a", "b", "", "c"]);
+/// ```
+pub fn splitx_trim<'word>(s: &'word str, sep: &'_ str) -> Vec<&'word str> {
+    splitx(s, sep)
+        .into_iter()
+        .map(|piece| piece.trim_ascii())
+        .collect()
+}
+
+/// Splits a string slice using a separator, trims ASCII whitespace from each piece and drops
+/// pieces that are empty after trimming
+///
+/// # Arguments
+///
+/// * `s` - The string slice to split
+/// * `sep` - The separator string to split on
+///
+/// # Returns
+///
+/// A vector of trimmed, non-empty string slices
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::split::splitx_trim_nonempty;
+///
+/// let result = splitx_trim_nonempty(" a , b ,, c ", ",");
+/// assert_eq!(result, vec!["a", "b", "c"]);
+/// ```
+pub fn splitx_trim_nonempty<'word>(s: &'word str, sep: &'_ str) -> Vec<&'word str> {
+    splitx_trim(s, sep)
+        .into_iter()
+        .filter(|piece| !piece.is_empty())
+        .collect()
+}
+
+/// Splits a string slice in two around the first occurrence of a separator
+///
+/// # Arguments
+///
+/// * `s` - The string slice to split
+/// * `sep` - The separator string to split on, which may span several characters
+///
+/// # Returns
+///
+/// The parts before and after the first separator, or `None` if the separator does not occur
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::split::split_once_x;
+///
+/// assert_eq!(split_once_x("key=value=extra", "="), Some(("key", "value=extra")));
+/// assert_eq!(split_once_x("no separator", "="), None);
+/// ```
+pub fn split_once_x<'word>(s: &'word str, sep: &'_ str) -> Option<(&'word str, &'word str)> {
+    s.split_once(sep)
+}
+
+/// Splits a string slice in two around the last occurrence of a separator
+///
+/// # Arguments
+///
+/// * `s` - The string slice to split
+/// * `sep` - The separator string to split on, which may span several characters
+///
+/// # Returns
+///
+/// The parts before and after the last separator, or `None` if the separator does not occur
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::split::rsplit_once_x;
+///
+/// assert_eq!(rsplit_once_x("key=value=extra", "="), Some(("key=value", "extra")));
+/// ```
+pub fn rsplit_once_x<'word>(s: &'word str, sep: &'_ str) -> Option<(&'word str, &'word str)> {
+    s.rsplit_once(sep)
+}
+
+/// Splits a string slice using a separator and drops empty pieces
+///
+/// Empty pieces come from consecutive separators or separators at either end of the input,
+/// as in `PATH`-style lists. Unlike [`splitx_trim_nonempty`], whitespace is kept as is.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to split
+/// * `sep` - The separator string to split on
+///
+/// # Returns
+///
+/// A vector of non-empty string slices
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::split::splitx_nonempty;
+///
+/// let result = splitx_nonempty("/usr/bin::/bin:", ":");
+/// assert_eq!(result, vec!["/usr/bin", "/bin"]);
+/// ```
+pub fn splitx_nonempty<'word>(s: &'word str, sep: &'_ str) -> Vec<&'word str> {
+    s.split(sep).filter(|piece| !piece.is_empty()).collect()
+}
+
+/// Splits a string slice using a separator, keeping the separators in the result
+///
+/// The result alternates between content and separator slices, starting and ending with
+/// content, so concatenating all elements reproduces the original string. Content slices can
+/// be empty when separators are adjacent or at the ends of the input.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to split
+/// * `sep` - The separator string to split on
+///
+/// # Returns
+///
+/// A vector of alternating content and separator slices
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::split::splitx_keep_sep;
+///
+/// let result = splitx_keep_sep("a,b,c", ",");
+/// assert_eq!(result, vec!["a", ",", "b", ",", "c"]);
+/// ```
+pub fn splitx_keep_sep<'word>(s: &'word str, sep: &'_ str) -> Vec<&'word str> {
+    // Return empty vector if input string is empty
+    if s.is_empty() {
+        return vec![];
+    }
+    let mut parts = Vec::new();
+    let mut last = 0;
+    for (idx, matched) in s.match_indices(sep) {
+        parts.push(&s[last..idx]);
+        parts.push(matched);
+        last = idx + matched.len();
+    }
+    parts.push(&s[last..]);
+    parts
+}
+
+/// Splits a string slice whenever any of the given separator characters is encountered
+///
+/// Consecutive separators produce empty slices, matching [`splitx`]. Use
+/// [`splitx_any_collapsed`] to collapse them.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to split
+/// * `seps` - The separator characters to split on
+///
+/// # Returns
+///
+/// A vector of string slices between separators
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::split::splitx_any;
+///
+/// let result = splitx_any("a,b;c d", &[',', ';', ' ']);
+/// assert_eq!(result, vec!["a", "b", "c", "d"]);
+/// ```
+pub fn splitx_any<'word>(s: &'word str, seps: &[char]) -> Vec<&'word str> {
+    // Return empty vector if input string is empty
+    if s.is_empty() {
+        return vec![];
+    }
+    s.split(seps).collect()
+}
+
+/// Splits a string slice on any of the given separator characters, collapsing consecutive
+/// separators
+///
+/// Runs of separators, including leading and trailing ones, never produce empty slices.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to split
+/// * `seps` - The separator characters to split on
+///
+/// # Returns
+///
+/// A vector of non-empty string slices between separators
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::split::splitx_any_collapsed;
+///
+/// let result = splitx_any_collapsed("a, b;;c", &[',', ';', ' ']);
+/// assert_eq!(result, vec!["a", "b", "c"]);
+/// ```
+pub fn splitx_any_collapsed<'word>(s: &'word str, seps: &[char]) -> Vec<&'word str> {
+    s.split(seps).filter(|piece| !piece.is_empty()).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -44,4 +366,114 @@ mod tests {
         assert_eq!(splitx("hello world", " "), vec!["hello", "world"]);
         assert_eq!(splitx("", ""), Vec::<&str>::new());
     }
+
+    /// Test the splitx_owned function outlives its input
+    #[test]
+    fn test_split_owned() {
+        let source = "a,b,c".to_string();
+        let owned = splitx_owned(&source, ",");
+        drop(source);
+        assert_eq!(owned, vec!["a", "b", "c"]);
+        assert_eq!(splitx_owned("", ","), Vec::<String>::new());
+    }
+
+    /// Test the splitx_unique function keeps first occurrences in order
+    #[test]
+    fn test_split_unique() {
+        assert_eq!(splitx_unique("a,b,a,c,b", ","), vec!["a", "b", "c"]);
+        assert_eq!(splitx_unique("x,,y,,", ","), vec!["x", "", "y"]);
+        assert_eq!(splitx_unique("same", ","), vec!["same"]);
+        assert_eq!(splitx_unique("", ","), Vec::<&str>::new());
+    }
+
+    /// Test the splitx_n function with various limits
+    #[test]
+    fn test_split_n() {
+        assert_eq!(splitx_n("a,b,c,d", ",", 2), vec!["a", "b,c,d"]);
+        assert_eq!(splitx_n("a,b,c,d", ",", 10), vec!["a", "b", "c", "d"]);
+        assert_eq!(splitx_n("a,b,c,d", ",", 1), vec!["a,b,c,d"]);
+        assert_eq!(splitx_n("a,b,c,d", ",", 0), Vec::<&str>::new());
+        assert_eq!(splitx_n("", ",", 2), Vec::<&str>::new());
+    }
+
+    /// Test the splitx_keep_sep function round-trips the input
+    #[test]
+    fn test_split_keep_sep() {
+        assert_eq!(splitx_keep_sep("a,b,c", ","), vec!["a", ",", "b", ",", "c"]);
+        assert_eq!(splitx_keep_sep("a::b", "::"), vec!["a", "::", "b"]);
+        assert_eq!(splitx_keep_sep(",a,", ","), vec!["", ",", "a", ",", ""]);
+        assert_eq!(splitx_keep_sep("abc", ","), vec!["abc"]);
+        assert_eq!(splitx_keep_sep("", ","), Vec::<&str>::new());
+
+        for original in ["a,b,c", ",,a,,", "no separators", "x, y, z"] {
+            assert_eq!(splitx_keep_sep(original, ",").concat(), original);
+        }
+    }
+
+    /// Test the splitx_any function with several separators
+    #[test]
+    fn test_split_any() {
+        assert_eq!(
+            splitx_any("a,b;c d", &[',', ';', ' ']),
+            vec!["a", "b", "c", "d"]
+        );
+        assert_eq!(splitx_any("a,;b", &[',', ';']), vec!["a", "", "b"]);
+        assert_eq!(splitx_any("abc", &[]), vec!["abc"]);
+        assert_eq!(splitx_any("", &[',']), Vec::<&str>::new());
+    }
+
+    /// Test the splitx_any_collapsed function drops empty pieces
+    #[test]
+    fn test_split_any_collapsed() {
+        assert_eq!(
+            splitx_any_collapsed(" a,;b  c; ", &[',', ';', ' ']),
+            vec!["a", "b", "c"]
+        );
+        assert_eq!(splitx_any_collapsed("", &[',']), Vec::<&str>::new());
+    }
+
+    /// Test the splitx_trim function keeps empty pieces
+    #[test]
+    fn test_split_trim() {
+        assert_eq!(splitx_trim(" a , b ,, c ", ","), vec!["a", "b", "", "c"]);
+        assert_eq!(splitx_trim("a\t,\nb", ","), vec!["a", "b"]);
+        assert_eq!(splitx_trim("", ","), Vec::<&str>::new());
+    }
+
+    /// Test the splitx_trim_nonempty function drops empty pieces
+    #[test]
+    fn test_split_trim_nonempty() {
+        assert_eq!(
+            splitx_trim_nonempty(" a , b ,, c ", ","),
+            vec!["a", "b", "c"]
+        );
+        assert_eq!(splitx_trim_nonempty(" , ,", ","), Vec::<&str>::new());
+    }
+
+    /// Test the splitx_nonempty function drops empty pieces
+    #[test]
+    fn test_split_nonempty() {
+        assert_eq!(splitx_nonempty(",a,,b,", ","), vec!["a", "b"]);
+        assert_eq!(splitx_nonempty(" a , b", ","), vec![" a ", " b"]);
+        assert_eq!(splitx_nonempty(",,,", ","), Vec::<&str>::new());
+        assert_eq!(splitx_nonempty("", ","), Vec::<&str>::new());
+    }
+
+    /// Test the split_once_x and rsplit_once_x functions around the first and last separator
+    #[test]
+    fn test_split_once() {
+        assert_eq!(
+            split_once_x("key=value=extra", "="),
+            Some(("key", "value=extra"))
+        );
+        assert_eq!(
+            rsplit_once_x("key=value=extra", "="),
+            Some(("key=value", "extra"))
+        );
+        assert_eq!(split_once_x("a::b::c", "::"), Some(("a", "b::c")));
+        assert_eq!(rsplit_once_x("a::b::c", "::"), Some(("a::b", "c")));
+        assert_eq!(split_once_x("=value", "="), Some(("", "value")));
+        assert_eq!(split_once_x("key", "="), None);
+        assert_eq!(rsplit_once_x("", "="), None);
+    }
 }
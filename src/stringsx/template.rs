@@ -0,0 +1,145 @@
+//! Provides lightweight `${name}` template interpolation.
+//!
+//! Available with the `std` feature. Variables are written as `${name}` and replaced with
+//! their value. A literal `${` is written as `$${`, and a `$` not followed by `{` is kept as
+//! is.
+//!
+//! # Example
+//! ```
+//! use std::collections::HashMap;
+//! use crate::toolkit::stringsx::template::render;
+//!
+//! let vars = HashMap::from([("name".to_string(), "world".to_string())]);
+//! assert_eq!(render("Hello, ${name}!", &vars).unwrap(), "Hello, world!");
+//! ```
+
+use std::collections::HashMap;
+
+use crate::errorsx::ErrorX;
+
+/// Renders a template by substituting `${name}` variables.
+///
+/// Every variable is resolved before an error is returned, so the error lists all missing
+/// names at once.
+///
+/// # Arguments
+///
+/// * `template` - The template text
+/// * `vars` - The variable values by name
+///
+/// # Returns
+///
+/// The rendered text, or an `ErrorX` if a variable has no value in `vars` or a `${` is never
+/// closed by a `}`.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use crate::toolkit::stringsx::template::render;
+///
+/// let vars = HashMap::from([("user".to_string(), "ada".to_string())]);
+/// assert_eq!(render("$${user} is ${user}", &vars).unwrap(), "${user} is ada");
+///
+/// let err = render("${user}@${host}", &vars).unwrap_err();
+/// assert_eq!(err.message(), "Unresolved template variables: host");
+/// ```
+pub fn render(template: &str, vars: &HashMap<String, String>) -> Result<String, ErrorX> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut missing: Vec<&str> = Vec::new();
+    let mut rest = template;
+
+    while let Some(idx) = rest.find('$') {
+        rendered.push_str(&rest[..idx]);
+        let tail = &rest[idx..];
+        if let Some(after) = tail.strip_prefix("$${") {
+            rendered.push_str("${");
+            rest = after;
+        } else if let Some(after) = tail.strip_prefix("${") {
+            let end = after.find('}').ok_or_else(|| {
+                ErrorX::builder("Unterminated template variable")
+                    .with_context(format!(
+                        "Missing '}}' after byte {}",
+                        template.len() - tail.len()
+                    ))
+                    .build()
+            })?;
+            let name = &after[..end];
+            match vars.get(name) {
+                Some(value) => rendered.push_str(value),
+                None if !missing.contains(&name) => missing.push(name),
+                None => {}
+            }
+            rest = &after[end + 1..];
+        } else {
+            rendered.push('$');
+            rest = &tail[1..];
+        }
+    }
+    rendered.push_str(rest);
+
+    if missing.is_empty() {
+        Ok(rendered)
+    } else {
+        Err(ErrorX::new(format!(
+            "Unresolved template variables: {}",
+            missing.join(", ")
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|&(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_render() {
+        let vars = vars(&[("name", "Ada"), ("count", "3")]);
+        assert_eq!(
+            render(
+                "Hi ${name}, you have ${count} new messages, ${name}.",
+                &vars
+            )
+            .unwrap(),
+            "Hi Ada, you have 3 new messages, Ada."
+        );
+        assert_eq!(render("no variables", &vars).unwrap(), "no variables");
+        assert_eq!(render("", &vars).unwrap(), "");
+    }
+
+    #[test]
+    fn test_render_escapes() {
+        let vars = vars(&[("price", "5")]);
+        assert_eq!(
+            render("$${price} = $${price}", &vars).unwrap(),
+            "${price} = ${price}"
+        );
+        assert_eq!(render("cost: $${price}", &vars).unwrap(), "cost: ${price}");
+        assert_eq!(render("$$${price}", &vars).unwrap(), "$${price}");
+        assert_eq!(render("$ ${price} $", &vars).unwrap(), "$ 5 $");
+    }
+
+    #[test]
+    fn test_render_missing_variables() {
+        let vars = vars(&[("user", "ada")]);
+        let err = render("${greeting} ${user}@${host}, ${greeting}", &vars).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "Unresolved template variables: greeting, host"
+        );
+    }
+
+    #[test]
+    fn test_render_unterminated_variable() {
+        let err = render("Hello ${name", &vars(&[("name", "Ada")])).unwrap_err();
+        assert_eq!(err.message(), "Unterminated template variable");
+        assert_eq!(err.context(), &vec!["Missing '}' after byte 6".to_string()]);
+    }
+}
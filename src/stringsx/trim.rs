@@ -0,0 +1,103 @@
+//! Trimming of custom character sets
+//!
+//! This module provides functions for trimming any of a set of characters from the ends of a
+//! string, without writing a closure for `str::trim_matches`.
+//! Functions include:
+//! - `trim_chars`: Trim the characters from both ends
+//! - `trim_start_chars`: Trim the characters from the start only
+//! - `trim_end_chars`: Trim the characters from the end only
+//!
+//! # Example
+//! ```
+//! use crate::toolkit::stringsx::trim::trim_chars;
+//!
+//! assert_eq!(trim_chars("__--hello--__", &['_', '-']), "hello");
+//! ```
+
+/// Trims any of the given characters from both ends of a string
+///
+/// # Arguments
+/// * `s` - The input string to trim
+/// * `chars` - The characters to remove
+///
+/// # Returns
+/// * A slice of `s` without leading or trailing characters from `chars`
+///
+/// # Examples
+/// ```
+/// use crate::toolkit::stringsx::trim::trim_chars;
+///
+/// assert_eq!(trim_chars("**bold**", &['*']), "bold");
+/// ```
+pub fn trim_chars<'a>(s: &'a str, chars: &[char]) -> &'a str {
+    s.trim_matches(chars)
+}
+
+/// Trims any of the given characters from the start of a string
+///
+/// # Arguments
+/// * `s` - The input string to trim
+/// * `chars` - The characters to remove
+///
+/// # Returns
+/// * A slice of `s` without leading characters from `chars`
+///
+/// # Examples
+/// ```
+/// use crate::toolkit::stringsx::trim::trim_start_chars;
+///
+/// assert_eq!(trim_start_chars("0042", &['0']), "42");
+/// ```
+pub fn trim_start_chars<'a>(s: &'a str, chars: &[char]) -> &'a str {
+    s.trim_start_matches(chars)
+}
+
+/// Trims any of the given characters from the end of a string
+///
+/// # Arguments
+/// * `s` - The input string to trim
+/// * `chars` - The characters to remove
+///
+/// # Returns
+/// * A slice of `s` without trailing characters from `chars`
+///
+/// # Examples
+/// ```
+/// use crate::toolkit::stringsx::trim::trim_end_chars;
+///
+/// assert_eq!(trim_end_chars("path/to/dir//", &['/']), "path/to/dir");
+/// ```
+pub fn trim_end_chars<'a>(s: &'a str, chars: &[char]) -> &'a str {
+    s.trim_end_matches(chars)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEPARATORS: &[char] = &['_', '-'];
+
+    #[test]
+    fn test_trim_chars() {
+        assert_eq!(trim_chars("__--hello--__", SEPARATORS), "hello");
+        assert_eq!(trim_chars("_-he-llo_-", SEPARATORS), "he-llo");
+    }
+
+    #[test]
+    fn test_trim_start_chars() {
+        assert_eq!(trim_start_chars("__--hello--__", SEPARATORS), "hello--__");
+    }
+
+    #[test]
+    fn test_trim_end_chars() {
+        assert_eq!(trim_end_chars("__--hello--__", SEPARATORS), "__--hello");
+    }
+
+    #[test]
+    fn test_trim_chars_edge_cases() {
+        assert_eq!(trim_chars("", SEPARATORS), "");
+        assert_eq!(trim_chars("_-_", SEPARATORS), "");
+        assert_eq!(trim_chars("hello", &[]), "hello");
+        assert_eq!(trim_chars("·héllo·", &['·']), "héllo");
+    }
+}
@@ -1,6 +1,15 @@
 //! Provides string truncation utilities.
+//!
+//! Grapheme-cluster aware truncation is available with the `unicode` feature.
 
-use std::str;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::str;
+
+#[cfg(feature = "unicode")]
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Truncates a string to a specified byte length while maintaining UTF-8 validity.
 ///
@@ -14,8 +23,8 @@ use std::str;
 ///
 /// # Returns
 ///
-/// A string slice containing the truncated string. If the input is already shorter than
-/// the specified length or if length is 0, returns the original string unchanged.
+/// A string slice containing the truncated string. If the input is no longer than the
+/// specified length or if length is 0, returns the original string unchanged.
 ///
 /// # Examples
 ///
@@ -26,7 +35,7 @@ use std::str;
 /// assert_eq!(truncate_byte_len("Hello,🚧", 7), "Hello,");
 /// ```
 pub fn truncate_byte_len(s: &str, length: usize) -> &str {
-    if length == 0 || s.len() < length {
+    if length == 0 || s.len() <= length {
         return s;
     }
 
@@ -38,9 +47,256 @@ pub fn truncate_byte_len(s: &str, length: usize) -> &str {
     &s[..valid_len]
 }
 
+/// Truncates a string to a specified byte length and reports whether it was shortened.
+///
+/// Behaves exactly like [`truncate_byte_len`], which is useful when the caller needs to add
+/// an ellipsis or log that the input was cut.
+///
+/// # Arguments
+///
+/// * `s` - The input string to truncate
+/// * `length` - The maximum byte length to truncate to
+///
+/// # Returns
+///
+/// A tuple of the truncated string slice and `true` if it is shorter than the input.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::truncate::truncate_byte_len_checked;
+///
+/// assert_eq!(truncate_byte_len_checked("Hello, World", 5), ("Hello", true));
+/// assert_eq!(truncate_byte_len_checked("Hello", 10), ("Hello", false));
+/// ```
+pub fn truncate_byte_len_checked(s: &str, length: usize) -> (&str, bool) {
+    let truncated = truncate_byte_len(s, length);
+    (truncated, truncated.len() < s.len())
+}
+
+/// Truncates a string to at most the specified number of characters.
+///
+/// Characters are counted as Unicode scalar values, so multibyte characters count once
+/// regardless of how many bytes they occupy.
+///
+/// # Arguments
+///
+/// * `s` - The input string to truncate
+/// * `max_chars` - The maximum number of characters to keep
+///
+/// # Returns
+///
+/// A string slice containing at most `max_chars` characters. If the input has fewer
+/// characters it is returned unchanged, and if `max_chars` is 0 an empty string is returned.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::truncate::truncate_char_len;
+///
+/// assert_eq!(truncate_char_len("Hello, World", 5), "Hello");
+/// assert_eq!(truncate_char_len("🚧🚧🚧", 2), "🚧🚧");
+/// ```
+pub fn truncate_char_len(s: &str, max_chars: usize) -> &str {
+    match s.char_indices().nth(max_chars) {
+        Some((idx, _)) => &s[..idx],
+        None => s,
+    }
+}
+
+/// Truncates a string to a character count, marking the elision with a suffix.
+///
+/// When truncation occurs the ellipsis is appended and the total output, ellipsis included,
+/// never exceeds `max_chars` characters.
+///
+/// # Arguments
+///
+/// * `s` - The input string to truncate
+/// * `max_chars` - The maximum number of characters in the output
+/// * `ellipsis` - The suffix marking the elision, e.g. `"…"` or `"..."`
+///
+/// # Returns
+///
+/// The input unchanged if it already fits within `max_chars`, otherwise the truncated string
+/// followed by the ellipsis. If `max_chars` leaves no room for content next to the ellipsis,
+/// the ellipsis truncated to `max_chars` characters is returned.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::truncate::truncate_with_ellipsis;
+///
+/// assert_eq!(truncate_with_ellipsis("Hello, World", 8, "…"), "Hello, …");
+/// assert_eq!(truncate_with_ellipsis("Hello", 8, "…"), "Hello");
+/// ```
+pub fn truncate_with_ellipsis(s: &str, max_chars: usize, ellipsis: &str) -> String {
+    if s.chars().count() <= max_chars {
+        return s.to_string();
+    }
+
+    let ellipsis_len = ellipsis.chars().count();
+    if max_chars <= ellipsis_len {
+        return truncate_char_len(ellipsis, max_chars).to_string();
+    }
+
+    format!(
+        "{}{}",
+        truncate_char_len(s, max_chars - ellipsis_len),
+        ellipsis
+    )
+}
+
+/// Truncates a string from the left, keeping at most the last `max_chars` characters.
+///
+/// Useful when the end of a string is the most informative part, such as file paths.
+///
+/// # Arguments
+///
+/// * `s` - The input string to truncate
+/// * `max_chars` - The maximum number of trailing characters to keep
+///
+/// # Returns
+///
+/// A string slice containing the last `max_chars` characters. If the input has fewer
+/// characters it is returned unchanged, and if `max_chars` is 0 an empty string is returned.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::truncate::truncate_left;
+///
+/// assert_eq!(truncate_left("src/errorsx/mod.rs", 6), "mod.rs");
+/// ```
+pub fn truncate_left(s: &str, max_chars: usize) -> &str {
+    let char_count = s.chars().count();
+    if char_count <= max_chars {
+        return s;
+    }
+    match s.char_indices().nth(char_count - max_chars) {
+        Some((idx, _)) => &s[idx..],
+        None => "",
+    }
+}
+
+/// Truncates a string to a character count by eliding its middle.
+///
+/// Both ends of the string are kept and joined by the ellipsis, with the total output never
+/// exceeding `max_chars` characters. When the kept characters cannot be split evenly, the
+/// start keeps one more than the end.
+///
+/// # Arguments
+///
+/// * `s` - The input string to truncate
+/// * `max_chars` - The maximum number of characters in the output
+/// * `ellipsis` - The marker placed where the middle was elided
+///
+/// # Returns
+///
+/// The input unchanged if it already fits within `max_chars`, otherwise the start and end of
+/// the string around the ellipsis. As with [`truncate_with_ellipsis`], if there is no room for
+/// content, the ellipsis truncated to `max_chars` characters is returned.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::truncate::truncate_middle;
+///
+/// assert_eq!(truncate_middle("src/errorsx/mod.rs", 11, "…"), "src/e…od.rs");
+/// ```
+pub fn truncate_middle(s: &str, max_chars: usize, ellipsis: &str) -> String {
+    if s.chars().count() <= max_chars {
+        return s.to_string();
+    }
+
+    let ellipsis_len = ellipsis.chars().count();
+    if max_chars <= ellipsis_len {
+        return truncate_char_len(ellipsis, max_chars).to_string();
+    }
+
+    let kept = max_chars - ellipsis_len;
+    let tail_len = kept / 2;
+    let head_len = kept - tail_len;
+    format!(
+        "{}{}{}",
+        truncate_char_len(s, head_len),
+        ellipsis,
+        truncate_left(s, tail_len)
+    )
+}
+
+/// Truncates a string to a character count without cutting words in half.
+///
+/// The string is cut at the last whitespace boundary at or before `max_chars` characters and
+/// trailing whitespace is trimmed from the result. If there is no whitespace within the limit,
+/// this falls back to a hard truncation like [`truncate_char_len`].
+///
+/// # Arguments
+///
+/// * `s` - The input string to truncate
+/// * `max_chars` - The maximum number of characters to keep
+///
+/// # Returns
+///
+/// A string slice ending on a word boundary where possible. If the input already fits within
+/// `max_chars` it is returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::truncate::truncate_at_word;
+///
+/// assert_eq!(truncate_at_word("the quick brown fox", 12), "the quick");
+/// assert_eq!(truncate_at_word("unbreakable", 6), "unbrea");
+/// ```
+pub fn truncate_at_word(s: &str, max_chars: usize) -> &str {
+    let Some((cut, next)) = s.char_indices().nth(max_chars) else {
+        return s;
+    };
+    let candidate = &s[..cut];
+    // A whitespace right after the limit means the candidate already ends on a word
+    if next.is_whitespace() {
+        return candidate.trim_end();
+    }
+    match candidate.rfind(char::is_whitespace) {
+        Some(idx) if !candidate[..idx].trim_end().is_empty() => candidate[..idx].trim_end(),
+        _ => candidate,
+    }
+}
+
+/// Truncates a string to at most the specified number of grapheme clusters.
+///
+/// Unlike [`truncate_char_len`], this never splits a user-perceived character such as a ZWJ
+/// emoji sequence (👨‍👩‍👧) or a letter followed by combining accents.
+///
+/// # Arguments
+///
+/// * `s` - The input string to truncate
+/// * `max_graphemes` - The maximum number of grapheme clusters to keep
+///
+/// # Returns
+///
+/// A string slice containing at most `max_graphemes` extended grapheme clusters. If the input
+/// has fewer clusters it is returned unchanged, and if `max_graphemes` is 0 an empty string is
+/// returned.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::truncate::truncate_grapheme_len;
+///
+/// assert_eq!(truncate_grapheme_len("👨‍👩‍👧👨‍👩‍👧", 1), "👨‍👩‍👧");
+/// ```
+#[cfg(feature = "unicode")]
+pub fn truncate_grapheme_len(s: &str, max_graphemes: usize) -> &str {
+    match s.grapheme_indices(true).nth(max_graphemes) {
+        Some((idx, _)) => &s[..idx],
+        None => s,
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::stringsx::truncate::truncate_byte_len;
+    use crate::stringsx::truncate::{truncate_byte_len, truncate_char_len, truncate_with_ellipsis};
 
     #[test]
     fn test_truncate() {
@@ -48,4 +304,118 @@ mod tests {
         assert_eq!(truncate_byte_len("Hello, World", 5), "Hello");
         assert_eq!(truncate_byte_len("Hello,🚧", 7), "Hello,");
     }
+
+    #[test]
+    fn test_truncate_exact_length() {
+        // Regression: a length equal to the byte length returns the input without scanning
+        for s in ["Test", "Hello,🚧", "🚧"] {
+            let truncated = truncate_byte_len(s, s.len());
+            assert_eq!(truncated, s);
+            assert_eq!(truncated.as_ptr(), s.as_ptr());
+        }
+    }
+
+    #[test]
+    fn test_truncate_byte_len_checked() {
+        use crate::stringsx::truncate::truncate_byte_len_checked;
+
+        assert_eq!(
+            truncate_byte_len_checked("Hello, World", 5),
+            ("Hello", true)
+        );
+        assert_eq!(truncate_byte_len_checked("Hello,🚧", 7), ("Hello,", true));
+        assert_eq!(truncate_byte_len_checked("Test", 10), ("Test", false));
+        assert_eq!(truncate_byte_len_checked("Test", 4), ("Test", false));
+        assert_eq!(truncate_byte_len_checked("Test", 0), ("Test", false));
+    }
+
+    #[test]
+    fn test_truncate_char_len() {
+        assert_eq!(truncate_char_len("Test", 10), "Test");
+        assert_eq!(truncate_char_len("Test", 4), "Test");
+        assert_eq!(truncate_char_len("Test", 0), "");
+        assert_eq!(truncate_char_len("Hello, World", 5), "Hello");
+        assert_eq!(truncate_char_len("Hi🚧🚧there", 4), "Hi🚧🚧");
+        assert_eq!(truncate_char_len("héllo", 2), "hé");
+    }
+
+    #[test]
+    fn test_truncate_with_ellipsis() {
+        assert_eq!(truncate_with_ellipsis("Hello, World", 8, "…"), "Hello, …");
+        assert_eq!(truncate_with_ellipsis("Hello, World", 8, "..."), "Hello...");
+        assert_eq!(truncate_with_ellipsis("Hello", 5, "…"), "Hello");
+        assert_eq!(truncate_with_ellipsis("🚧🚧🚧🚧", 3, "…"), "🚧🚧…");
+    }
+
+    #[test]
+    fn test_truncate_with_ellipsis_shorter_than_ellipsis() {
+        // Without room for content, the ellipsis itself is truncated
+        assert_eq!(truncate_with_ellipsis("Hello, World", 2, "..."), "..");
+        assert_eq!(truncate_with_ellipsis("Hello, World", 3, "..."), "...");
+        assert_eq!(truncate_with_ellipsis("Hello, World", 0, "..."), "");
+    }
+
+    #[test]
+    fn test_truncate_left() {
+        use crate::stringsx::truncate::truncate_left;
+
+        assert_eq!(truncate_left("src/errorsx/mod.rs", 6), "mod.rs");
+        assert_eq!(truncate_left("Test", 10), "Test");
+        assert_eq!(truncate_left("Test", 0), "");
+        assert_eq!(truncate_left("🚧abc🚧", 2), "c🚧");
+    }
+
+    #[test]
+    fn test_truncate_middle() {
+        use crate::stringsx::truncate::truncate_middle;
+
+        assert_eq!(
+            truncate_middle("src/errorsx/mod.rs", 11, "…"),
+            "src/e…od.rs"
+        );
+        assert_eq!(
+            truncate_middle("src/errorsx/mod.rs", 12, "/…/"),
+            "src/e/…/d.rs"
+        );
+        assert_eq!(truncate_middle("short", 10, "…"), "short");
+        assert_eq!(truncate_middle("🚧🚧🚧🚧🚧", 4, "…"), "🚧🚧…🚧");
+        assert_eq!(truncate_middle("Hello, World", 2, "..."), "..");
+    }
+
+    #[test]
+    fn test_truncate_at_word() {
+        use crate::stringsx::truncate::truncate_at_word;
+
+        let text = "the quick brown fox";
+        assert_eq!(truncate_at_word(text, 30), text);
+        assert_eq!(truncate_at_word(text, 19), text);
+        assert_eq!(truncate_at_word(text, 18), "the quick brown");
+        assert_eq!(truncate_at_word(text, 12), "the quick");
+        assert_eq!(truncate_at_word(text, 10), "the quick");
+        assert_eq!(truncate_at_word(text, 9), "the quick");
+        assert_eq!(truncate_at_word(text, 5), "the");
+        assert_eq!(truncate_at_word(text, 2), "th");
+        assert_eq!(truncate_at_word(text, 0), "");
+        assert_eq!(truncate_at_word("  leading", 4), "  le");
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_truncate_grapheme_len() {
+        use crate::stringsx::truncate::truncate_grapheme_len;
+
+        let family = "👨\u{200d}👩\u{200d}👧";
+        let text = format!("{family}{family}ab");
+        assert_eq!(truncate_grapheme_len(&text, 1), family);
+        assert_eq!(
+            truncate_grapheme_len(&text, 3),
+            format!("{family}{family}a")
+        );
+        assert_eq!(truncate_grapheme_len(&text, 10), text);
+        assert_eq!(truncate_grapheme_len(&text, 0), "");
+
+        let accented = "e\u{301}e\u{301}";
+        assert_eq!(truncate_grapheme_len(accented, 1), "e\u{301}");
+        assert_eq!(truncate_char_len(accented, 1), "e");
+    }
 }
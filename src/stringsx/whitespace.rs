@@ -0,0 +1,98 @@
+//! Provides whitespace normalization utilities.
+//!
+//! Whitespace follows the Unicode `White_Space` property, so tabs, newlines and non-breaking
+//! spaces all count.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::stringsx::{lines::split_lines, words::words};
+
+/// Trims a string and replaces every internal run of whitespace with a single ASCII space.
+///
+/// # Arguments
+///
+/// * `s` - The input string to normalize
+///
+/// # Returns
+///
+/// A new string with no leading, trailing or repeated whitespace.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::whitespace::collapse_whitespace;
+///
+/// assert_eq!(collapse_whitespace("  a\t\t b \n c  "), "a b c");
+/// ```
+pub fn collapse_whitespace(s: &str) -> String {
+    words(s).collect::<Vec<_>>().join(" ")
+}
+
+/// Collapses whitespace like [`collapse_whitespace`] while keeping line and paragraph breaks.
+///
+/// Each line is collapsed on its own. Consecutive blank lines are reduced to a single empty
+/// line marking a paragraph break, and blank lines at the start and end are removed. Lines are
+/// split on `\n`, `\r\n` and `\r`, and always rejoined with `\n`.
+///
+/// # Arguments
+///
+/// * `s` - The input string to normalize
+///
+/// # Returns
+///
+/// A new string with collapsed lines separated by `\n` and paragraphs separated by `\n\n`.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::whitespace::collapse_whitespace_preserve_newlines;
+///
+/// let input = "  First   line\n second\tline \n\n\n  Next  paragraph ";
+/// assert_eq!(
+///     collapse_whitespace_preserve_newlines(input),
+///     "First line\nsecond line\n\nNext paragraph"
+/// );
+/// ```
+pub fn collapse_whitespace_preserve_newlines(s: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    for line in split_lines(s).into_iter().map(collapse_whitespace) {
+        let previous_blank = lines.last().is_none_or(String::is_empty);
+        if !line.is_empty() || !previous_blank {
+            lines.push(line);
+        }
+    }
+    if lines.last().is_some_and(String::is_empty) {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collapse_whitespace() {
+        assert_eq!(collapse_whitespace("  a\t\t b \n c  "), "a b c");
+        assert_eq!(collapse_whitespace("already clean"), "already clean");
+        assert_eq!(collapse_whitespace(" \t\n "), "");
+        assert_eq!(collapse_whitespace("a\u{a0}\u{a0}b"), "a b");
+    }
+
+    #[test]
+    fn test_collapse_whitespace_preserve_newlines() {
+        assert_eq!(
+            collapse_whitespace_preserve_newlines("  a\t\t b \n c  "),
+            "a b\nc"
+        );
+        assert_eq!(
+            collapse_whitespace_preserve_newlines("\n\none\r\n\r\n\r\ntwo\n\n"),
+            "one\n\ntwo"
+        );
+        assert_eq!(
+            collapse_whitespace_preserve_newlines("a\n \t \nb"),
+            "a\n\nb"
+        );
+        assert_eq!(collapse_whitespace_preserve_newlines(" \n \n "), "");
+    }
+}
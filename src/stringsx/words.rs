@@ -0,0 +1,72 @@
+//! Provides word iteration and counting utilities.
+//!
+//! Words are maximal runs of non-whitespace characters, where whitespace follows the Unicode
+//! `White_Space` property. Leading, trailing and repeated whitespace never produces empty
+//! words.
+
+/// Returns an iterator over the words of a string.
+///
+/// # Arguments
+///
+/// * `s` - The input string to split into words
+///
+/// # Returns
+///
+/// An iterator yielding each word as a string slice, in order.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::words::words;
+///
+/// let found: Vec<&str> = words("  hello\tworld\n ").collect();
+/// assert_eq!(found, vec!["hello", "world"]);
+/// ```
+pub fn words(s: &str) -> impl Iterator<Item = &str> {
+    s.split_whitespace()
+}
+
+/// Counts the words in a string.
+///
+/// # Arguments
+///
+/// * `s` - The input string to count words in
+///
+/// # Returns
+///
+/// The number of words, as yielded by [`words`].
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::words::count_words;
+///
+/// assert_eq!(count_words("The quick  brown fox"), 4);
+/// ```
+pub fn count_words(s: &str) -> usize {
+    words(s).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_words() {
+        assert_eq!(
+            words("  hello\tworld\n ").collect::<Vec<_>>(),
+            ["hello", "world"]
+        );
+        assert_eq!(words("one").collect::<Vec<_>>(), ["one"]);
+        assert_eq!(words("a\u{3000}b").collect::<Vec<_>>(), ["a", "b"]);
+    }
+
+    #[test]
+    fn test_count_words() {
+        assert_eq!(count_words("  hello\tworld\n "), 2);
+        assert_eq!(count_words(""), 0);
+        assert_eq!(count_words(" \t\r\n"), 0);
+        assert_eq!(count_words("a  b   c"), 3);
+    }
+}
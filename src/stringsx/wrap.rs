@@ -0,0 +1,118 @@
+//! Provides paragraph wrapping for terminal output.
+//!
+//! Widths are measured in characters. Lines are broken at whitespace, and any run of
+//! whitespace between words becomes a single space.
+//!
+//! # Example
+//! ```
+//! use crate::toolkit::stringsx::wrap::wrap;
+//!
+//! assert_eq!(wrap("print this help text", 10), vec!["print this", "help text"]);
+//! ```
+
+use alloc::{string::String, vec::Vec};
+
+use super::words::words;
+
+/// Wraps a paragraph into lines no wider than `width` characters.
+///
+/// Words are never split unless they are longer than `width` on their own, in which case
+/// they are hard-broken into `width`-character pieces. A `width` of zero is treated as one.
+///
+/// # Arguments
+///
+/// * `s` - The paragraph to wrap
+/// * `width` - The maximum number of characters per line
+///
+/// # Returns
+///
+/// The wrapped lines, without trailing whitespace. Empty if `s` contains no words.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::stringsx::wrap::wrap;
+///
+/// assert_eq!(wrap("a b c", 3), vec!["a b", "c"]);
+/// assert_eq!(wrap("abcdefgh", 3), vec!["abc", "def", "gh"]);
+/// ```
+pub fn wrap(s: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_len = 0;
+
+    for word in words(s) {
+        let word_len = word.chars().count();
+        if line_len > 0 && line_len + 1 + word_len <= width {
+            line.push(' ');
+            line.push_str(word);
+            line_len += 1 + word_len;
+            continue;
+        }
+        if line_len > 0 {
+            lines.push(core::mem::take(&mut line));
+        }
+
+        // Break over-long words into full-width pieces, the last one starts the next line
+        let mut chars = word.chars().peekable();
+        while chars.peek().is_some() {
+            line = chars.by_ref().take(width).collect();
+            if chars.peek().is_some() {
+                lines.push(core::mem::take(&mut line));
+            }
+        }
+        line_len = line.chars().count();
+    }
+    if line_len > 0 {
+        lines.push(line);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_sentence() {
+        assert_eq!(
+            wrap("The quick brown fox jumps over the lazy dog", 10),
+            vec!["The quick", "brown fox", "jumps over", "the lazy", "dog"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_lines_fit_width() {
+        let text = "Wrapping keeps every line within the requested width when possible";
+        for line in wrap(text, 10) {
+            assert!(line.chars().count() <= 10, "{line:?} is too wide");
+        }
+    }
+
+    #[test]
+    fn test_wrap_long_word() {
+        assert_eq!(
+            wrap("see supercalifragilistic now", 10),
+            vec!["see", "supercalif", "ragilistic", "now"]
+        );
+        assert_eq!(wrap("abcdefghij", 10), vec!["abcdefghij"]);
+    }
+
+    #[test]
+    fn test_wrap_whitespace() {
+        assert_eq!(wrap("  one\n\ttwo   three ", 10), vec!["one two", "three"]);
+        assert_eq!(wrap("", 10), Vec::<String>::new());
+        assert_eq!(wrap("   ", 10), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_wrap_zero_width() {
+        assert_eq!(wrap("ab c", 0), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_wrap_counts_chars() {
+        assert_eq!(wrap("héllo wörld", 5), vec!["héllo", "wörld"]);
+    }
+}
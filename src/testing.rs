@@ -0,0 +1,144 @@
+//! Assertion macros for testing code that returns [`ErrorX`](crate::errorsx::ErrorX)
+//!
+//! Available with the `testing` feature, typically enabled only for dev-dependencies. The
+//! macros are exported at the crate root:
+//! - `assert_errorx!`: Asserts that a result is an `Err` whose message contains a substring
+//! - `assert_status!`: Asserts that an error has a given status code
+//!
+//! Both panic with a message showing the expected and actual values on failure.
+//!
+//! # Example
+//! ```
+//! use crate::toolkit::{assert_errorx, assert_status, errorsx::ErrorX};
+//!
+//! let result: Result<(), ErrorX> = Err(ErrorX::from_status(404));
+//! assert_errorx!(result, contains: "Not Found");
+//! assert_status!(result.unwrap_err(), 404);
+//! ```
+
+/// Asserts that a `Result` is an `Err` holding an [`ErrorX`](crate::errorsx::ErrorX) whose
+/// message contains a substring
+///
+/// The result is borrowed, so it can still be used after the assertion.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::{assert_errorx, errorsx::ErrorX};
+///
+/// let result: Result<u32, ErrorX> = Err(ErrorX::new("user 42 not found"));
+/// assert_errorx!(result, contains: "not found");
+/// ```
+#[macro_export]
+macro_rules! assert_errorx {
+    ($result:expr, contains: $needle:expr $(,)?) => {
+        match &$result {
+            ::std::result::Result::Ok(_) => ::std::panic!(
+                "assertion failed: expected an ErrorX whose message contains {:?}, got Ok",
+                $needle
+            ),
+            ::std::result::Result::Err(err) => {
+                let err: &$crate::errorsx::ErrorX = err;
+                if !err.message().contains($needle) {
+                    ::std::panic!(
+                        "assertion failed: expected ErrorX message to contain {:?}, got {:?}",
+                        $needle,
+                        err.message()
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that an [`ErrorX`](crate::errorsx::ErrorX) has a given status code
+///
+/// The error is borrowed, so it can still be used after the assertion.
+///
+/// # Examples
+///
+/// ```
+/// use crate::toolkit::{assert_status, errorsx::ErrorX};
+///
+/// let err = ErrorX::builder("Unavailable").with_status_code(503).build();
+/// assert_status!(err, 503);
+/// ```
+#[macro_export]
+macro_rules! assert_status {
+    ($err:expr, $status_code:expr $(,)?) => {{
+        let err: &$crate::errorsx::ErrorX = &$err;
+        let expected: u32 = $status_code;
+        match err.status_code() {
+            ::std::option::Option::Some(actual) if *actual == expected => {}
+            ::std::option::Option::Some(actual) => ::std::panic!(
+                "assertion failed: expected ErrorX status code {}, got {} (message: {:?})",
+                expected,
+                actual,
+                err.message()
+            ),
+            ::std::option::Option::None => ::std::panic!(
+                "assertion failed: expected ErrorX status code {}, got none (message: {:?})",
+                expected,
+                err.message()
+            ),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errorsx::ErrorX;
+
+    fn not_found() -> Result<u32, ErrorX> {
+        Err(ErrorX::builder("user 42 not found")
+            .with_status_code(404)
+            .build())
+    }
+
+    #[test]
+    fn test_assert_errorx_passes() {
+        let result = not_found();
+        assert_errorx!(result, contains: "not found");
+        assert_errorx!(result, contains: "user 42");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected ErrorX message to contain \"timed out\"")]
+    fn test_assert_errorx_wrong_message() {
+        assert_errorx!(not_found(), contains: "timed out");
+    }
+
+    #[test]
+    #[should_panic(expected = "got Ok")]
+    fn test_assert_errorx_ok() {
+        let result: Result<u32, ErrorX> = Ok(1);
+        assert_errorx!(result, contains: "anything");
+    }
+
+    #[test]
+    fn test_assert_status_passes() {
+        let err = not_found().unwrap_err();
+        assert_status!(err, 404);
+        assert_eq!(err.message(), "user 42 not found");
+    }
+
+    #[test]
+    fn test_assert_status_in_expression_position() {
+        match not_found() {
+            Ok(_) => panic!("expected an error"),
+            Err(err) => assert_status!(err, 404),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "expected ErrorX status code 500, got 404")]
+    fn test_assert_status_wrong_code() {
+        assert_status!(not_found().unwrap_err(), 500);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected ErrorX status code 500, got none")]
+    fn test_assert_status_unset() {
+        assert_status!(ErrorX::new("No status"), 500);
+    }
+}
@@ -1,9 +1,45 @@
 //! UUID Generation Utilities
 //!
 //! This module provides functionality for generating UUIDs (Universally Unique Identifiers).
-//! Currently supports generating random version 4 UUIDs.
+//! Currently supports generating random version 4 UUIDs, name-based version 3 and 5 UUIDs and
+//! time-ordered version 7 UUIDs, as well as parsing and validating UUID strings, reading
+//! back embedded timestamps and converting to and from compact encodings. The [`ulid`]
+//! submodule generates lexicographically sortable ULIDs.
 
-use uuid::Uuid;
+use std::{
+    cmp::Ordering,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use uuid::{NoContext, Timestamp, Uuid, Version};
+
+pub use uuid::Variant;
+
+use crate::errorsx::ErrorX;
+
+pub mod ulid;
+
+/// Number of 100ns ticks between the Gregorian epoch (1582-10-15) and the Unix epoch
+const GREGORIAN_TICKS_TO_UNIX_EPOCH: u64 = 0x01B2_1DD2_1381_4000;
+
+/// Digits used for Base62 encoding, in ascending order of value
+const BASE62_ALPHABET: &[u8; 62] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Number of Base62 digits needed to represent any 128-bit value
+const BASE62_LEN: usize = 22;
+
+/// Namespace for fully-qualified domain names
+pub const NAMESPACE_DNS: Uuid = Uuid::NAMESPACE_DNS;
+
+/// Namespace for URLs
+pub const NAMESPACE_URL: Uuid = Uuid::NAMESPACE_URL;
+
+/// Namespace for ISO object identifiers (OIDs)
+pub const NAMESPACE_OID: Uuid = Uuid::NAMESPACE_OID;
+
+/// Namespace for X.500 distinguished names
+pub const NAMESPACE_X500: Uuid = Uuid::NAMESPACE_X500;
 
 /// Generates a new random UUID v4
 ///
@@ -22,6 +58,595 @@ pub fn new_v4() -> Uuid {
     Uuid::new_v4()
 }
 
+/// Generates a batch of random UUID v4 values
+///
+/// # Arguments
+///
+/// * `n` - The number of UUIDs to generate
+///
+/// # Returns
+///
+/// A vector of `n` randomly generated version 4 UUIDs
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::new_v4_batch;
+///
+/// let uuids = new_v4_batch(10);
+/// assert_eq!(uuids.len(), 10);
+/// ```
+pub fn new_v4_batch(n: usize) -> Vec<Uuid> {
+    let mut uuids = Vec::with_capacity(n);
+    uuids.extend(new_v4_iter().take(n));
+    uuids
+}
+
+/// Returns an endless iterator of random UUID v4 values
+///
+/// Useful for streaming generation without allocating the full set up front.
+///
+/// # Returns
+///
+/// An iterator yielding randomly generated version 4 UUIDs
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::new_v4_iter;
+///
+/// let uuids: Vec<_> = new_v4_iter().take(3).collect();
+/// assert_eq!(uuids.len(), 3);
+/// ```
+pub fn new_v4_iter() -> impl Iterator<Item = Uuid> {
+    std::iter::repeat_with(new_v4)
+}
+
+/// Generates a deterministic, version 4 formatted UUID from a seed
+///
+/// The random bits are produced by a SplitMix64 generator seeded with `seed`, so the same seed
+/// always yields the same UUID. This is meant for reproducible tests and fixtures only: the
+/// output is predictable and must not be used where unguessable identifiers are required.
+/// [`new_v4`] is unaffected and keeps using cryptographically secure randomness.
+///
+/// # Arguments
+///
+/// * `seed` - The seed for the generator
+///
+/// # Returns
+///
+/// A version 4 UUID determined entirely by `seed`
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::new_v4_seeded;
+///
+/// assert_eq!(new_v4_seeded(42), new_v4_seeded(42));
+/// assert_eq!(new_v4_seeded(42).get_version_num(), 4);
+/// ```
+pub fn new_v4_seeded(seed: u64) -> Uuid {
+    let mut state = seed;
+    let mut next = || {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    };
+    let mut bytes = [0u8; 16];
+    bytes[..8].copy_from_slice(&next().to_be_bytes());
+    bytes[8..].copy_from_slice(&next().to_be_bytes());
+    uuid::Builder::from_random_bytes(bytes).into_uuid()
+}
+
+/// Generates a name-based UUID v3 from a namespace and a name
+///
+/// The UUID is derived from the MD5 hash of the namespace and name, so the same inputs always
+/// produce the same UUID. Prefer [`new_v5`] unless compatibility with systems that already use
+/// version 3 is required.
+///
+/// # Arguments
+///
+/// * `namespace` - The namespace UUID, e.g. [`NAMESPACE_DNS`]
+/// * `name` - The name to hash within the namespace
+///
+/// # Returns
+///
+/// A deterministic version 3 UUID
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::{new_v3, NAMESPACE_DNS};
+///
+/// let uuid = new_v3(NAMESPACE_DNS, b"example.com");
+/// assert_eq!(uuid, new_v3(NAMESPACE_DNS, b"example.com"));
+/// ```
+pub fn new_v3(namespace: Uuid, name: &[u8]) -> Uuid {
+    Uuid::new_v3(&namespace, name)
+}
+
+/// Generates a name-based UUID v5 from a namespace and a name
+///
+/// The UUID is derived from the SHA-1 hash of the namespace and name, so the same inputs
+/// always produce the same UUID.
+///
+/// # Arguments
+///
+/// * `namespace` - The namespace UUID, e.g. [`NAMESPACE_DNS`]
+/// * `name` - The name to hash within the namespace
+///
+/// # Returns
+///
+/// A deterministic version 5 UUID
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::{new_v5, NAMESPACE_DNS};
+///
+/// let uuid = new_v5(NAMESPACE_DNS, b"example.com");
+/// assert_eq!(uuid, new_v5(NAMESPACE_DNS, b"example.com"));
+/// ```
+pub fn new_v5(namespace: Uuid, name: &[u8]) -> Uuid {
+    Uuid::new_v5(&namespace, name)
+}
+
+/// Generates a new time-ordered UUID v7 using the current time
+///
+/// The UUID embeds the current Unix timestamp in milliseconds followed by random bits, as
+/// specified by RFC 9562. UUIDs generated by the same process are guaranteed to be ordered
+/// by their creation.
+///
+/// # Returns
+///
+/// A version 7 UUID for the current time
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::new_v7;
+///
+/// let first = new_v7();
+/// let second = new_v7();
+/// assert!(first < second);
+/// ```
+pub fn new_v7() -> Uuid {
+    Uuid::now_v7()
+}
+
+/// Generates a new UUID v7 for the given time
+///
+/// Times before the Unix epoch are clamped to the epoch.
+///
+/// # Arguments
+///
+/// * `ts` - The time to embed in the UUID
+///
+/// # Returns
+///
+/// A version 7 UUID embedding the given time in milliseconds
+///
+/// # Example
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use crate::toolkit::uuidx::new_v7_at;
+///
+/// let uuid = new_v7_at(UNIX_EPOCH + Duration::from_millis(1_700_000_000_000));
+/// assert_eq!(uuid.get_version_num(), 7);
+/// ```
+pub fn new_v7_at(ts: SystemTime) -> Uuid {
+    let since_epoch = ts.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let timestamp =
+        Timestamp::from_unix(NoContext, since_epoch.as_secs(), since_epoch.subsec_nanos());
+    Uuid::new_v7(timestamp)
+}
+
+/// Extracts the embedded Unix timestamp, in milliseconds, from a time-based UUID
+///
+/// Version 7 UUIDs store Unix milliseconds directly. Version 1 and 6 UUIDs store 100ns ticks
+/// since the Gregorian epoch, which are converted to Unix milliseconds.
+///
+/// # Arguments
+///
+/// * `uuid` - The UUID to inspect
+///
+/// # Returns
+///
+/// * `Some(u64)` - The Unix timestamp in milliseconds
+/// * `None` - If the UUID version carries no timestamp, or a v1/v6 timestamp predates the
+///   Unix epoch
+///
+/// # Example
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use crate::toolkit::uuidx::{new_v4, new_v7_at, timestamp_millis};
+///
+/// let uuid = new_v7_at(UNIX_EPOCH + Duration::from_millis(1_700_000_000_000));
+/// assert_eq!(timestamp_millis(&uuid), Some(1_700_000_000_000));
+/// assert_eq!(timestamp_millis(&new_v4()), None);
+/// ```
+pub fn timestamp_millis(uuid: &Uuid) -> Option<u64> {
+    let timestamp = uuid.get_timestamp()?;
+    if uuid.get_version() != Some(Version::SortRand) {
+        let (ticks, _) = timestamp.to_gregorian();
+        if ticks < GREGORIAN_TICKS_TO_UNIX_EPOCH {
+            return None;
+        }
+    }
+    let (secs, nanos) = timestamp.to_unix();
+    Some(secs * 1000 + u64::from(nanos) / 1_000_000)
+}
+
+/// Compares two UUIDs by their embedded timestamps, for sorting by creation time
+///
+/// Time-based UUIDs (v1, v6 and v7) are ordered by their timestamp at its full precision, with
+/// ties broken by byte order. UUIDs without a timestamp, such as v4, sort before all
+/// time-based ones and in byte order among themselves, which keeps the ordering total so it is
+/// safe to use with `sort_by`.
+///
+/// # Arguments
+///
+/// * `a` - The first UUID
+/// * `b` - The second UUID
+///
+/// # Returns
+///
+/// The ordering of `a` relative to `b`
+///
+/// # Example
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use crate::toolkit::uuidx::{cmp_by_time, new_v7_at};
+///
+/// let later = new_v7_at(UNIX_EPOCH + Duration::from_secs(2_000_000_000));
+/// let earlier = new_v7_at(UNIX_EPOCH + Duration::from_secs(1_000_000_000));
+/// let mut uuids = vec![later, earlier];
+/// uuids.sort_by(cmp_by_time);
+/// assert_eq!(uuids, vec![earlier, later]);
+/// ```
+pub fn cmp_by_time(a: &Uuid, b: &Uuid) -> Ordering {
+    let time = |uuid: &Uuid| uuid.get_timestamp().map(|timestamp| timestamp.to_unix());
+    time(a).cmp(&time(b)).then_with(|| a.cmp(b))
+}
+
+/// Returns the version number of a UUID
+///
+/// # Arguments
+///
+/// * `uuid` - The UUID to inspect
+///
+/// # Returns
+///
+/// The version number, `0` for the nil UUID, or `None` if the version bits do not hold a
+/// known version
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::{new_v7, version};
+///
+/// assert_eq!(version(&new_v7()), Some(7));
+/// ```
+pub fn version(uuid: &Uuid) -> Option<usize> {
+    uuid.get_version().map(|_| uuid.get_version_num())
+}
+
+/// Returns whether a UUID is a name-based version 3 (MD5) UUID
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::{is_v3, new_v3, NAMESPACE_DNS};
+///
+/// assert!(is_v3(&new_v3(NAMESPACE_DNS, b"example.com")));
+/// ```
+pub fn is_v3(uuid: &Uuid) -> bool {
+    uuid.get_version() == Some(Version::Md5)
+}
+
+/// Returns whether a UUID is a random version 4 UUID
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::{is_v4, new_v4};
+///
+/// assert!(is_v4(&new_v4()));
+/// ```
+pub fn is_v4(uuid: &Uuid) -> bool {
+    uuid.get_version() == Some(Version::Random)
+}
+
+/// Returns whether a UUID is a name-based version 5 (SHA-1) UUID
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::{is_v5, new_v5, NAMESPACE_DNS};
+///
+/// assert!(is_v5(&new_v5(NAMESPACE_DNS, b"example.com")));
+/// ```
+pub fn is_v5(uuid: &Uuid) -> bool {
+    uuid.get_version() == Some(Version::Sha1)
+}
+
+/// Returns whether a UUID is a time-ordered version 7 UUID
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::{is_v7, new_v7};
+///
+/// assert!(is_v7(&new_v7()));
+/// ```
+pub fn is_v7(uuid: &Uuid) -> bool {
+    uuid.get_version() == Some(Version::SortRand)
+}
+
+/// Returns the variant of a UUID, which determines how the rest of its bits are laid out
+///
+/// # Arguments
+///
+/// * `uuid` - The UUID to inspect
+///
+/// # Returns
+///
+/// The [`Variant`], [`Variant::RFC4122`] for every UUID generated by this module
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::{new_v4, variant, Variant};
+///
+/// assert_eq!(variant(&new_v4()), Variant::RFC4122);
+/// ```
+pub fn variant(uuid: &Uuid) -> Variant {
+    uuid.get_variant()
+}
+
+/// Returns the nil UUID, with all 128 bits set to zero
+///
+/// # Returns
+///
+/// The nil UUID `00000000-0000-0000-0000-000000000000`
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::nil;
+///
+/// assert!(nil().is_nil());
+/// ```
+pub fn nil() -> Uuid {
+    Uuid::nil()
+}
+
+/// Returns the max UUID, with all 128 bits set to one
+///
+/// # Returns
+///
+/// The max UUID `ffffffff-ffff-ffff-ffff-ffffffffffff`
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::max;
+///
+/// assert!(max().is_max());
+/// ```
+pub fn max() -> Uuid {
+    Uuid::max()
+}
+
+/// Parses a UUID from a string
+///
+/// Accepts the hyphenated (`67e55044-10b1-426f-9247-bb680e5fe0c8`), simple
+/// (`67e5504410b1426f9247bb680e5fe0c8`), URN (`urn:uuid:67e55044-...`) and braced
+/// (`{67e55044-...}`) forms.
+///
+/// # Arguments
+///
+/// * `s` - The string to parse
+///
+/// # Returns
+///
+/// * `Ok(Uuid)` - The parsed UUID
+/// * `Err(ErrorX)` - An error naming the offending input, with context describing what was
+///   malformed
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::parse;
+///
+/// let uuid = parse("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+/// assert_eq!(uuid.get_version_num(), 4);
+/// assert!(parse("not-a-uuid").is_err());
+/// ```
+#[track_caller]
+pub fn parse(s: &str) -> Result<Uuid, ErrorX> {
+    Uuid::parse_str(s).map_err(|err| {
+        ErrorX::builder(format!("Invalid UUID string: {:?}", s))
+            .with_context(err.to_string())
+            .with_source(err)
+            .build()
+    })
+}
+
+/// Checks whether a string is a valid UUID in any of the forms accepted by [`parse`]
+///
+/// # Arguments
+///
+/// * `s` - The string to check
+///
+/// # Returns
+///
+/// `true` if the string parses as a UUID, `false` otherwise
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::is_valid;
+///
+/// assert!(is_valid("{67e55044-10b1-426f-9247-bb680e5fe0c8}"));
+/// assert!(!is_valid("67e55044"));
+/// ```
+pub fn is_valid(s: &str) -> bool {
+    Uuid::parse_str(s).is_ok()
+}
+
+/// Formats a UUID as 32 lowercase hex characters without hyphens
+///
+/// # Arguments
+///
+/// * `uuid` - The UUID to format
+///
+/// # Returns
+///
+/// The simple, hyphen-less representation of the UUID
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::{parse, to_simple};
+///
+/// let uuid = parse("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+/// assert_eq!(to_simple(&uuid), "67e5504410b1426f9247bb680e5fe0c8");
+/// ```
+pub fn to_simple(uuid: &Uuid) -> String {
+    uuid.simple().to_string()
+}
+
+/// Parses a UUID from exactly 32 hex characters without hyphens
+///
+/// Unlike [`parse`], other forms such as the hyphenated one are rejected. Both uppercase and
+/// lowercase hex digits are accepted.
+///
+/// # Arguments
+///
+/// * `s` - The string to parse
+///
+/// # Returns
+///
+/// * `Ok(Uuid)` - The parsed UUID
+/// * `Err(ErrorX)` - An error naming the offending input, with context describing whether the
+///   length or the characters were wrong
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::from_simple;
+///
+/// let uuid = from_simple("67e5504410b1426f9247bb680e5fe0c8").unwrap();
+/// assert_eq!(uuid.get_version_num(), 4);
+/// assert!(from_simple("67e55044-10b1-426f-9247-bb680e5fe0c8").is_err());
+/// ```
+#[track_caller]
+pub fn from_simple(s: &str) -> Result<Uuid, ErrorX> {
+    let invalid = |reason: String| {
+        ErrorX::builder(format!("Invalid simple UUID string: {:?}", s))
+            .with_context(reason)
+            .build()
+    };
+    if s.len() != 32 {
+        return Err(invalid(format!(
+            "expected exactly 32 hex characters, found {} bytes",
+            s.len()
+        )));
+    }
+    if let Some(c) = s.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(invalid(format!("invalid character {:?}", c)));
+    }
+    parse(s)
+}
+
+/// Encodes a UUID as a fixed-width, URL-safe Base62 string
+///
+/// The 128-bit value is treated as a big-endian integer and written with the digits
+/// `0-9A-Za-z`, left-padded with `0` to 22 characters so that every UUID encodes to the same
+/// length and the encoding sorts like the UUID itself.
+///
+/// # Arguments
+///
+/// * `uuid` - The UUID to encode
+///
+/// # Returns
+///
+/// A 22-character Base62 string
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::{max, nil, to_base62};
+///
+/// assert_eq!(to_base62(&nil()), "0000000000000000000000");
+/// assert_eq!(to_base62(&max()), "7n42DGM5Tflk9n8mt7Fhc7");
+/// ```
+pub fn to_base62(uuid: &Uuid) -> String {
+    let mut value = uuid.as_u128();
+    let mut digits = [b'0'; BASE62_LEN];
+    for digit in digits.iter_mut().rev() {
+        *digit = BASE62_ALPHABET[(value % 62) as usize];
+        value /= 62;
+    }
+    digits.iter().map(|&digit| digit as char).collect()
+}
+
+/// Decodes a Base62 string produced by [`to_base62`] back into a UUID
+///
+/// Shorter strings without the leading `0` padding are accepted as well.
+///
+/// # Arguments
+///
+/// * `s` - The Base62 string to decode
+///
+/// # Returns
+///
+/// * `Ok(Uuid)` - The decoded UUID
+/// * `Err(ErrorX)` - An error naming the offending input if it is empty, contains characters
+///   outside `0-9A-Za-z`, or encodes a value that does not fit in 128 bits
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::{from_base62, new_v4, to_base62};
+///
+/// let uuid = new_v4();
+/// assert_eq!(from_base62(&to_base62(&uuid)).unwrap(), uuid);
+/// assert!(from_base62("not base62!").is_err());
+/// ```
+#[track_caller]
+pub fn from_base62(s: &str) -> Result<Uuid, ErrorX> {
+    let invalid = |reason: &str| {
+        ErrorX::builder(format!("Invalid Base62 UUID string: {:?}", s))
+            .with_context(reason)
+            .build()
+    };
+    if s.is_empty() {
+        return Err(invalid("input is empty"));
+    }
+
+    let mut value: u128 = 0;
+    for c in s.chars() {
+        let digit = match c {
+            '0'..='9' => c as u128 - '0' as u128,
+            'A'..='Z' => c as u128 - 'A' as u128 + 10,
+            'a'..='z' => c as u128 - 'a' as u128 + 36,
+            _ => return Err(invalid(&format!("invalid character {:?}", c))),
+        };
+        value = value
+            .checked_mul(62)
+            .and_then(|value| value.checked_add(digit))
+            .ok_or_else(|| invalid("value does not fit in 128 bits"))?;
+    }
+    Ok(Uuid::from_u128(value))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -31,4 +656,246 @@ mod tests {
         let uuid = new_v4();
         assert_eq!(uuid.get_version_num(), 4);
     }
+
+    #[test]
+    fn test_new_v4_batch() {
+        use std::collections::HashSet;
+
+        let uuids = new_v4_batch(1000);
+        assert_eq!(uuids.len(), 1000);
+        assert_eq!(uuids.iter().collect::<HashSet<_>>().len(), 1000);
+        assert!(new_v4_batch(0).is_empty());
+    }
+
+    #[test]
+    fn test_new_v4_iter() {
+        let uuids: Vec<Uuid> = new_v4_iter().take(5).collect();
+        assert_eq!(uuids.len(), 5);
+        assert!(uuids.iter().all(|uuid| uuid.get_version_num() == 4));
+    }
+
+    #[test]
+    fn test_new_v3() {
+        let uuid = new_v3(NAMESPACE_DNS, b"www.example.com");
+        assert_eq!(uuid.get_version_num(), 3);
+        assert_eq!(uuid.to_string(), "5df41881-3aed-3515-88a7-2f4a814cf09e");
+        assert_ne!(uuid, new_v3(NAMESPACE_URL, b"www.example.com"));
+    }
+
+    #[test]
+    fn test_new_v5() {
+        let uuid = new_v5(NAMESPACE_DNS, b"example.com");
+        assert_eq!(uuid.get_version_num(), 5);
+        assert_eq!(uuid.to_string(), "cfbff0d1-9375-5685-968c-48ce8b15ae17");
+        assert_eq!(uuid, new_v5(NAMESPACE_DNS, b"example.com"));
+        assert_ne!(uuid, new_v5(NAMESPACE_URL, b"example.com"));
+    }
+
+    #[test]
+    fn test_timestamp_millis() {
+        use std::time::Duration;
+
+        let now = SystemTime::now();
+        let expected = now.duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+        let recovered = timestamp_millis(&new_v7_at(now)).unwrap();
+        assert!(recovered.abs_diff(expected) <= 1);
+
+        let at = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+        assert_eq!(timestamp_millis(&new_v7_at(at)), Some(1_700_000_000_123));
+    }
+
+    #[test]
+    fn test_timestamp_millis_v1() {
+        let ticks = GREGORIAN_TICKS_TO_UNIX_EPOCH + 1_700_000_000_123 * 10_000;
+        let v1 = uuid::Builder::from_gregorian_timestamp(ticks, 0, &[1, 2, 3, 4, 5, 6]).into_uuid();
+        assert_eq!(timestamp_millis(&v1), Some(1_700_000_000_123));
+
+        let before_epoch = uuid::Builder::from_gregorian_timestamp(0, 0, &[0; 6]).into_uuid();
+        assert_eq!(timestamp_millis(&before_epoch), None);
+    }
+
+    #[test]
+    fn test_timestamp_millis_none() {
+        assert_eq!(timestamp_millis(&new_v4()), None);
+        assert_eq!(
+            timestamp_millis(&new_v5(NAMESPACE_DNS, b"example.com")),
+            None
+        );
+        assert_eq!(timestamp_millis(&nil()), None);
+    }
+
+    #[test]
+    fn test_nil() {
+        let uuid = nil();
+        assert_eq!(uuid.as_bytes(), &[0u8; 16]);
+        assert_eq!(uuid.get_version_num(), 0);
+    }
+
+    #[test]
+    fn test_max() {
+        assert_eq!(max().to_string(), "ffffffff-ffff-ffff-ffff-ffffffffffff");
+        assert!(nil() < max());
+    }
+
+    #[test]
+    fn test_parse() {
+        let expected = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        for input in [
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "67e5504410b1426f9247bb680e5fe0c8",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+        ] {
+            assert_eq!(parse(input).unwrap(), expected);
+        }
+
+        let err = parse("67e55044-10b1-426f-9247-bb680e5fe0cz").unwrap_err();
+        assert!(err
+            .message()
+            .contains("67e55044-10b1-426f-9247-bb680e5fe0cz"));
+        assert!(!err.context().is_empty());
+        assert_eq!(err.location().file(), "src/uuidx/mod.rs");
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(is_valid("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+        assert!(!is_valid(""));
+        assert!(!is_valid("67e55044-10b1-426f-9247"));
+    }
+
+    #[test]
+    fn test_new_v7() {
+        let uuids: Vec<Uuid> = (0..100).map(|_| new_v7()).collect();
+        assert!(uuids.iter().all(|uuid| uuid.get_version_num() == 7));
+        assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_new_v7_at() {
+        use std::time::Duration;
+
+        let earlier = new_v7_at(UNIX_EPOCH + Duration::from_millis(1_700_000_000_000));
+        let later = new_v7_at(UNIX_EPOCH + Duration::from_millis(1_700_000_000_001));
+        assert_eq!(earlier.get_version_num(), 7);
+        assert_eq!(later.get_version_num(), 7);
+        assert!(earlier < later);
+    }
+
+    #[test]
+    fn test_base62_round_trip() {
+        for uuid in new_v4_iter().take(100).chain([nil(), max()]) {
+            let encoded = to_base62(&uuid);
+            assert_eq!(encoded.len(), 22);
+            assert_eq!(from_base62(&encoded).unwrap(), uuid);
+        }
+        assert_eq!(from_base62("1").unwrap(), Uuid::from_u128(1));
+    }
+
+    #[test]
+    fn test_from_base62_invalid() {
+        assert!(from_base62("").is_err());
+        assert!(from_base62("abc-def").is_err());
+        assert!(from_base62("7n42DGM5Tflk9n8mt7Fhc8").is_err());
+
+        let err = from_base62("ab_cd").unwrap_err();
+        assert!(err.message().contains("ab_cd"));
+        assert_eq!(err.location().file(), "src/uuidx/mod.rs");
+    }
+
+    #[test]
+    fn test_simple_round_trip() {
+        for uuid in new_v4_iter().take(10) {
+            let simple = to_simple(&uuid);
+            assert_eq!(simple.len(), 32);
+            assert!(!simple.contains('-'));
+            assert_eq!(from_simple(&simple).unwrap(), uuid);
+        }
+        assert_eq!(
+            from_simple("67E5504410B1426F9247BB680E5FE0C8").unwrap(),
+            parse("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_simple_invalid_length() {
+        let err = from_simple("67e5504410b1426f").unwrap_err();
+        assert!(err.message().contains("67e5504410b1426f"));
+        assert!(err.context()[0].contains("32"));
+        assert!(from_simple("67e55044-10b1-426f-9247-bb680e5fe0c8").is_err());
+        assert!(from_simple("").is_err());
+    }
+
+    #[test]
+    fn test_from_simple_invalid_character() {
+        assert!(from_simple("67e5504410b1426f9247bb680e5fe0cz").is_err());
+    }
+
+    #[test]
+    fn test_cmp_by_time() {
+        use std::time::Duration;
+
+        let chronological: Vec<Uuid> = (0..20)
+            .map(|i| new_v7_at(UNIX_EPOCH + Duration::from_millis(1_700_000_000_000 + i * 7)))
+            .collect();
+        // Reverse and interleave the halves to shuffle deterministically
+        let mut shuffled: Vec<Uuid> = chronological.iter().rev().step_by(2).copied().collect();
+        shuffled.extend(chronological.iter().step_by(2));
+        assert_ne!(shuffled, chronological);
+
+        shuffled.sort_by(cmp_by_time);
+        assert_eq!(shuffled, chronological);
+    }
+
+    #[test]
+    fn test_cmp_by_time_fallbacks() {
+        use std::time::Duration;
+
+        let at = UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+        let (a, b) = (new_v7_at(at), new_v7_at(at));
+        assert_eq!(cmp_by_time(&a, &b), a.cmp(&b));
+
+        let random = new_v4();
+        assert_eq!(cmp_by_time(&random, &a), Ordering::Less);
+        assert_eq!(cmp_by_time(&nil(), &max()), Ordering::Less);
+        assert_eq!(cmp_by_time(&a, &a), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_new_v4_seeded() {
+        let uuid = new_v4_seeded(7);
+        assert_eq!(uuid, new_v4_seeded(7));
+        assert_eq!(uuid.get_version_num(), 4);
+        assert_eq!(uuid.get_variant(), uuid::Variant::RFC4122);
+        assert_ne!(uuid, new_v4_seeded(8));
+        assert_ne!(new_v4_seeded(0), nil());
+    }
+
+    #[test]
+    fn test_version_predicates() {
+        let cases = [
+            (new_v3(NAMESPACE_DNS, b"example.com"), 3),
+            (new_v4(), 4),
+            (new_v5(NAMESPACE_DNS, b"example.com"), 5),
+            (new_v7(), 7),
+        ];
+        for (uuid, expected) in cases {
+            assert_eq!(version(&uuid), Some(expected));
+            assert_eq!(is_v3(&uuid), expected == 3);
+            assert_eq!(is_v4(&uuid), expected == 4);
+            assert_eq!(is_v5(&uuid), expected == 5);
+            assert_eq!(is_v7(&uuid), expected == 7);
+            assert_eq!(variant(&uuid), Variant::RFC4122);
+        }
+    }
+
+    #[test]
+    fn test_version_sentinels() {
+        assert_eq!(version(&nil()), Some(0));
+        assert!(!is_v4(&nil()));
+        assert_eq!(variant(&nil()), Variant::NCS);
+        assert_eq!(variant(&max()), Variant::Future);
+        let unknown = Uuid::from_u128(0x0000_0000_0000_9000_8000_0000_0000_0000);
+        assert_eq!(version(&unknown), None);
+    }
 }
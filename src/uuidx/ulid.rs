@@ -0,0 +1,209 @@
+//! ULID Generation Utilities
+//!
+//! ULIDs are 128-bit identifiers made of a 48-bit millisecond Unix timestamp followed by 80
+//! random bits, written as 26 characters of Crockford base32. Unlike UUID strings they sort
+//! lexicographically in creation order.
+//!
+//! # Example
+//!
+//! ```
+//! use crate::toolkit::uuidx::ulid::{new_ulid_monotonic, ulid_timestamp};
+//!
+//! let first = new_ulid_monotonic();
+//! let second = new_ulid_monotonic();
+//! assert!(first < second);
+//! assert!(ulid_timestamp(&first).is_some());
+//! ```
+
+use std::{
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use uuid::Uuid;
+
+/// Crockford base32 digits, which leave out `I`, `L`, `O` and `U` to avoid confusion
+const CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Number of characters in an encoded ULID
+const ULID_LEN: usize = 26;
+
+/// Number of random bits following the timestamp
+const RANDOM_BITS: u32 = 80;
+
+/// Mask selecting the random part of a ULID
+const RANDOM_MASK: u128 = (1 << RANDOM_BITS) - 1;
+
+/// The most recent monotonic ULID, as its timestamp and random part
+static LAST_MONOTONIC: Mutex<(u64, u128)> = Mutex::new((0, 0));
+
+/// Returns the current Unix time in milliseconds, clamped to 0 before the epoch
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|since_epoch| since_epoch.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// Returns 80 random bits, taken from the fully random low 62 bits of two v4 UUIDs
+fn random_bits() -> u128 {
+    let low_mask = (1u128 << 62) - 1;
+    let high = Uuid::new_v4().as_u128() & low_mask;
+    let low = Uuid::new_v4().as_u128() & low_mask;
+    ((high << 62) | low) & RANDOM_MASK
+}
+
+/// Encodes a timestamp and random part as a 26-character ULID string
+fn encode(millis: u64, random: u128) -> String {
+    let mut value = (u128::from(millis) << RANDOM_BITS) | (random & RANDOM_MASK);
+    let mut digits = [b'0'; ULID_LEN];
+    for digit in digits.iter_mut().rev() {
+        *digit = CROCKFORD_ALPHABET[(value & 0x1F) as usize];
+        value >>= 5;
+    }
+    digits.iter().map(|&digit| digit as char).collect()
+}
+
+/// Generates a new ULID for the current time
+///
+/// ULIDs created within the same millisecond are ordered randomly. Use
+/// [`new_ulid_monotonic`] when strict ordering is required.
+///
+/// # Returns
+///
+/// A 26-character Crockford base32 ULID string
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::ulid::new_ulid;
+///
+/// assert_eq!(new_ulid().len(), 26);
+/// ```
+pub fn new_ulid() -> String {
+    encode(now_millis(), random_bits())
+}
+
+/// Generates a new ULID that is strictly greater than any previously generated by this function
+///
+/// Within the same millisecond, or if the clock moves backwards, the random part of the last
+/// ULID is incremented instead of drawing new randomness. Should the random part overflow, the
+/// timestamp is advanced by one millisecond.
+///
+/// # Returns
+///
+/// A 26-character Crockford base32 ULID string
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::ulid::new_ulid_monotonic;
+///
+/// let ulids: Vec<String> = (0..10).map(|_| new_ulid_monotonic()).collect();
+/// assert!(ulids.windows(2).all(|pair| pair[0] < pair[1]));
+/// ```
+pub fn new_ulid_monotonic() -> String {
+    let now = now_millis();
+    // A poisoned lock still holds a valid last value, so keep going with it
+    let mut last = LAST_MONOTONIC
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let (last_millis, last_random) = *last;
+
+    let next = if now > last_millis {
+        (now, random_bits())
+    } else if last_random < RANDOM_MASK {
+        (last_millis, last_random + 1)
+    } else {
+        (last_millis + 1, 0)
+    };
+    *last = next;
+    encode(next.0, next.1)
+}
+
+/// Extracts the millisecond Unix timestamp from a ULID string
+///
+/// Lowercase characters are accepted.
+///
+/// # Arguments
+///
+/// * `s` - The ULID string
+///
+/// # Returns
+///
+/// * `Some(u64)` - The milliseconds since the Unix epoch encoded in the ULID
+/// * `None` - If the string is not a valid 26-character ULID
+///
+/// # Example
+///
+/// ```
+/// use crate::toolkit::uuidx::ulid::ulid_timestamp;
+///
+/// assert_eq!(ulid_timestamp("01ARZ3NDEKTSV4RRFFQ69G5FAV"), Some(1_469_922_850_259));
+/// assert_eq!(ulid_timestamp("not-a-ulid"), None);
+/// ```
+pub fn ulid_timestamp(s: &str) -> Option<u64> {
+    if s.len() != ULID_LEN {
+        return None;
+    }
+    let mut value: u128 = 0;
+    for (idx, c) in s.bytes().enumerate() {
+        let digit = CROCKFORD_ALPHABET
+            .iter()
+            .position(|&d| d == c.to_ascii_uppercase())?;
+        // The first character only carries the top 3 of the 128 bits
+        if idx == 0 && digit > 7 {
+            return None;
+        }
+        value = (value << 5) | digit as u128;
+    }
+    Some((value >> RANDOM_BITS) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_ulid() {
+        let ulid = new_ulid();
+        assert_eq!(ulid.len(), 26);
+        assert!(ulid.bytes().all(|c| CROCKFORD_ALPHABET.contains(&c)));
+
+        let millis = ulid_timestamp(&ulid).unwrap();
+        assert!(now_millis() - millis < 1_000);
+    }
+
+    #[test]
+    fn test_new_ulid_monotonic() {
+        let ulids: Vec<String> = (0..1_000).map(|_| new_ulid_monotonic()).collect();
+        assert!(ulids.iter().all(|ulid| ulid.len() == 26));
+        assert!(ulids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_encode_same_millisecond_sorts() {
+        let first = encode(1_700_000_000_000, 41);
+        let second = encode(1_700_000_000_000, 42);
+        assert!(first < second);
+        assert_eq!(ulid_timestamp(&first), ulid_timestamp(&second));
+        assert_eq!(encode(0, RANDOM_MASK), "0000000000ZZZZZZZZZZZZZZZZ");
+        assert_eq!(encode(0, 0).len(), 26);
+    }
+
+    #[test]
+    fn test_ulid_timestamp() {
+        assert_eq!(
+            ulid_timestamp("01ARZ3NDEKTSV4RRFFQ69G5FAV"),
+            Some(1_469_922_850_259)
+        );
+        assert_eq!(
+            ulid_timestamp("01arz3ndektsv4rrffq69g5fav"),
+            Some(1_469_922_850_259)
+        );
+        assert_eq!(ulid_timestamp(""), None);
+        assert_eq!(ulid_timestamp("01ARZ3NDEKTSV4RRFFQ69G5FA"), None);
+        assert_eq!(ulid_timestamp("01ARZ3NDEKTSV4RRFFQ69G5FAU"), None);
+        assert_eq!(ulid_timestamp("81ARZ3NDEKTSV4RRFFQ69G5FAV"), None);
+    }
+}